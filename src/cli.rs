@@ -17,6 +17,10 @@ use crate::utils::UtilsCommand;
 #[command(about = "Dev workflow CLI", long_about = None)]
 #[command(version)]
 pub struct Cli {
+    /// Print a per-phase timing breakdown to stderr when the command finishes
+    #[arg(long, global = true)]
+    pub timings: bool,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -63,7 +67,7 @@ pub enum Command {
         cmd: Option<DocsCommand>,
     },
 
-    /// Cron job management (add, list, remove)
+    /// Cron job management (add, list, remove, export, import)
     Cron {
         #[command(subcommand)]
         cmd: Option<CronCommand>,
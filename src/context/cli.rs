@@ -1,31 +1,61 @@
-use clap::{Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand};
+
+use super::config::parse_duration;
 
 #[derive(Debug, Subcommand)]
 pub enum ContextCommand {
-    /// Track a file as loaded in context
+    /// Track file(s) as loaded in context (accepts directories and globs)
     Track(TrackArgs),
-    /// Check if a file is in context
+    /// Check if a file is in context (directories get a roll-up)
     Check(CheckArgs),
-    /// Show summary of all tracked files
-    Summary,
+    /// Show summary of tracked files, grouped by directory
+    Summary(SummaryArgs),
+    /// Drop stale or deleted-file entries
+    Prune(PruneArgs),
     /// Clear all tracked files
     Clear,
 }
 
 #[derive(Debug, Args)]
 pub struct TrackArgs {
-    /// File path(s) to track
+    /// File path(s), directories or globs (e.g. 'src/**/*.rs') to track
     #[arg(required = true)]
     pub paths: Vec<String>,
 }
 
 #[derive(Debug, Args)]
 pub struct CheckArgs {
-    /// File path(s) to check
+    /// File or directory path(s) to check
     #[arg(required = true)]
     pub paths: Vec<String>,
 }
 
+#[derive(Debug, Args)]
+pub struct SummaryArgs {
+    /// Sort files and groups by size, lines or age (newest first)
+    #[arg(long, value_enum, default_value_t = SummarySort::Age)]
+    pub sort: SummarySort,
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("criteria").required(true).multiple(true)))]
+pub struct PruneArgs {
+    /// Drop entries tracked longer ago than this (e.g. 30m, 2h, 1d)
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration, group = "criteria")]
+    pub older_than: Option<u64>,
+    /// Drop entries whose file no longer exists
+    #[arg(long, group = "criteria")]
+    pub missing: bool,
+}
+
+/// Sort order for `context summary`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SummarySort {
+    Size,
+    Lines,
+    Age,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,7 +110,43 @@ mod tests {
     #[test]
     fn parse_summary() {
         let cli = TestCli::try_parse_from(["test", "summary"]).unwrap();
-        assert!(matches!(cli.cmd, ContextCommand::Summary));
+        assert!(matches!(
+            cli.cmd,
+            ContextCommand::Summary(SummaryArgs {
+                sort: SummarySort::Age
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_summary_sort() {
+        let cli = TestCli::try_parse_from(["test", "summary", "--sort", "size"]).unwrap();
+        assert!(matches!(
+            cli.cmd,
+            ContextCommand::Summary(SummaryArgs {
+                sort: SummarySort::Size
+            })
+        ));
+        assert!(TestCli::try_parse_from(["test", "summary", "--sort", "name"]).is_err());
+    }
+
+    #[test]
+    fn parse_prune() {
+        let cli =
+            TestCli::try_parse_from(["test", "prune", "--older-than", "2h", "--missing"]).unwrap();
+        if let ContextCommand::Prune(args) = cli.cmd {
+            assert_eq!(args.older_than, Some(7200));
+            assert!(args.missing);
+        } else {
+            panic!("Expected Prune");
+        }
+    }
+
+    #[test]
+    fn prune_requires_criteria() {
+        assert!(TestCli::try_parse_from(["test", "prune"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "prune", "--older-than", "2x"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "prune", "--missing"]).is_ok());
     }
 
     #[test]
@@ -121,7 +187,9 @@ mod tests {
 
     #[test]
     fn context_command_debug() {
-        let cmd = ContextCommand::Summary;
+        let cmd = ContextCommand::Summary(SummaryArgs {
+            sort: SummarySort::Age,
+        });
         let debug = format!("{:?}", cmd);
         assert!(debug.contains("Summary"));
     }
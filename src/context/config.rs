@@ -0,0 +1,93 @@
+//! Context tracking configuration (`[context]` in settings.toml)
+
+use anyhow::Result;
+use serde::{Deserialize, Deserializer};
+
+use crate::util::load_settings_section_or_warn;
+
+/// `[context]` section of the settings file
+#[derive(Debug, Default, Deserialize)]
+struct ContextConfig {
+    /// Drop entries older than this on load (e.g. "4h"), in seconds
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    expire_after: Option<u64>,
+}
+
+fn deserialize_duration<'de, D: Deserializer<'de>>(de: D) -> Result<Option<u64>, D::Error> {
+    let s = String::deserialize(de)?;
+    parse_duration(&s)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+/// Configured auto-expiry in seconds, if any. A broken settings file must
+/// not block `clear`/`prune`, which are how a user recovers, so it only
+/// warns and runs without expiry.
+#[cfg(not(tarpaulin_include))]
+pub fn load_expire_after() -> Option<u64> {
+    load_settings_section_or_warn::<ContextConfig>("context").and_then(|c| c.expire_after)
+}
+
+/// Parse `context.expire_after` from settings TOML
+#[cfg(test)]
+pub fn expire_after_from_toml(content: &str) -> Result<Option<u64>> {
+    let config: Option<ContextConfig> =
+        crate::util::settings_section_from_toml(content, "context")?;
+    Ok(config.and_then(|c| c.expire_after))
+}
+
+/// Parse a duration like `90s`, `30m`, `2h` or `1d` into seconds
+pub fn parse_duration(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (num, unit) = s.split_at(split);
+    let value: u64 = num
+        .parse()
+        .map_err(|_| format!("invalid duration '{}' (e.g. 30m, 2h, 1d)", s))?;
+    let scale = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => {
+            return Err(format!(
+                "invalid duration unit in '{}' (use s, m, h or d)",
+                s
+            ))
+        }
+    };
+    value
+        .checked_mul(scale)
+        .ok_or_else(|| format!("duration '{}' is too large", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_units() {
+        assert_eq!(parse_duration("90s"), Ok(90));
+        assert_eq!(parse_duration("30m"), Ok(1800));
+        assert_eq!(parse_duration("2h"), Ok(7200));
+        assert_eq!(parse_duration(" 1d "), Ok(86400));
+    }
+
+    #[test]
+    fn parse_duration_rejects_bad_input() {
+        assert!(parse_duration("2").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("2w").is_err());
+        assert!(parse_duration("-1h").is_err());
+        assert!(parse_duration("999999999999999999d").is_err());
+    }
+
+    #[test]
+    fn expire_after_from_settings() {
+        let toml = "[newrelic]\naccount_id = 1\n\n[context]\nexpire_after = \"4h\"\n";
+        assert_eq!(expire_after_from_toml(toml).unwrap(), Some(14400));
+        assert_eq!(expire_after_from_toml("[context]\n").unwrap(), None);
+        assert_eq!(expire_after_from_toml("").unwrap(), None);
+        assert!(expire_after_from_toml("[context]\nexpire_after = \"soon\"\n").is_err());
+    }
+}
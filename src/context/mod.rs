@@ -1,4 +1,5 @@
 mod cli;
+mod config;
 mod service;
 mod store;
 mod types;
@@ -12,7 +13,8 @@ pub async fn run_command(cmd: ContextCommand) -> Result<()> {
     match cmd {
         ContextCommand::Track(args) => service::track(&args.paths).await,
         ContextCommand::Check(args) => service::check(&args.paths).await,
-        ContextCommand::Summary => service::summary().await,
+        ContextCommand::Summary(args) => service::summary(args.sort).await,
+        ContextCommand::Prune(args) => service::prune(&args).await,
         ContextCommand::Clear => service::clear().await,
     }
 }
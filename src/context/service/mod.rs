@@ -1,15 +1,27 @@
 use anyhow::{Context, Result};
+use std::collections::HashSet;
 use std::fs;
 use std::io::BufRead;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+use super::cli::SummarySort;
 use super::store::{default_store, ContextStore};
-use super::types::{ContextEntry, ContextState, FileStatus};
+use super::types::{
+    ContextEntry, ContextState, DirRollup, FileStatus, RollupCounts, RollupState, TrackSummary,
+};
+use crate::util::{timings, walk};
 
+/// Rough bytes-per-token ratio used for token estimates
+const BYTES_PER_TOKEN: u64 = 4;
+
+mod prune;
+mod summary;
 #[cfg(test)]
 mod tests;
 
+pub use prune::prune;
+
 /// Track file(s) as loaded in context
 pub async fn track(paths: &[String]) -> Result<()> {
     let store = default_store()?;
@@ -19,24 +31,75 @@ pub async fn track(paths: &[String]) -> Result<()> {
 /// Track files using a specific store (for testing)
 pub fn track_with_store(store: &impl ContextStore, paths: &[String]) -> Result<()> {
     let mut state = store.load()?;
+    let files = {
+        let _span = timings::span("walk: context");
+        expand_paths(paths)?
+    };
+    let mut summary = TrackSummary::default();
 
-    for path_str in paths {
-        let path = resolve_path(path_str)?;
-        let (size, line_count) = get_file_info(&path)?;
-        let entry = ContextEntry::new(path.clone(), size, line_count);
-        state.track(entry);
+    for path in files {
+        let (size, line_count) = {
+            let _span = timings::span("io: file info");
+            get_file_info(&path)?
+        };
         println!(
             "Tracked: {} ({} lines, {} bytes)",
             path.display(),
             line_count,
             size
         );
+        summary.add(size, line_count);
+        state.track(ContextEntry::new(path, size, line_count));
+    }
+
+    if summary.files > 1 {
+        println!();
+        println!("{}", format_track_summary(&summary));
     }
 
     store.save(&state)?;
     Ok(())
 }
 
+/// Expand path arguments: globs and directories become their files
+fn expand_paths(paths: &[String]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for path_str in paths {
+        if walk::has_glob_chars(path_str) {
+            let matched = walk::expand_glob(path_str)?;
+            if matched.is_empty() {
+                anyhow::bail!("No files match: {}", path_str);
+            }
+            for path in matched {
+                files.push(resolve_path(&path.to_string_lossy())?);
+            }
+            continue;
+        }
+
+        let path = resolve_path(path_str)?;
+        if path.is_dir() {
+            files.extend(walk::walk_files(&path, false)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    files.retain(|path| seen.insert(path.clone()));
+    Ok(files)
+}
+
+/// Format the totals added by a multi-file track
+fn format_track_summary(summary: &TrackSummary) -> String {
+    format!(
+        "Added: {} files, {} lines, ~{} tokens",
+        summary.files,
+        summary.lines,
+        summary.bytes / BYTES_PER_TOKEN
+    )
+}
+
 /// Check if file(s) are in context
 pub async fn check(paths: &[String]) -> Result<()> {
     let store = default_store()?;
@@ -50,6 +113,11 @@ pub fn check_with_store(store: &impl ContextStore, paths: &[String]) -> Result<(
 
     for path_str in paths {
         let path = resolve_path(path_str)?;
+        if path.is_dir() {
+            let rollup = rollup_dir(&state, &path)?;
+            println!("{}", format_rollup(&path, &rollup));
+            continue;
+        }
         let status = get_file_status(&state, &path, now)?;
         print_file_status(&status);
     }
@@ -57,14 +125,94 @@ pub fn check_with_store(store: &impl ContextStore, paths: &[String]) -> Result<(
     Ok(())
 }
 
+/// Aggregate context status for every file under a directory
+fn rollup_dir(state: &ContextState, dir: &Path) -> Result<DirRollup> {
+    let _span = timings::span("walk: context");
+    let mut rollup = DirRollup::default();
+
+    for file in walk::walk_files(dir, false)? {
+        let rel = file.strip_prefix(dir).unwrap_or(&file);
+        let group = match rel.parent().and_then(|p| p.components().next()) {
+            Some(first) => first.as_os_str().to_string_lossy().to_string(),
+            None => ".".to_string(),
+        };
+        rollup.add(&group, rollup_state(state, &file));
+    }
+
+    Ok(rollup)
+}
+
+/// Classify a file as tracked, untracked or stale
+fn rollup_state(state: &ContextState, path: &Path) -> RollupState {
+    match state.get(path) {
+        None => RollupState::Untracked,
+        Some(entry) if is_stale(entry, path) => RollupState::Stale,
+        Some(_) => RollupState::Tracked,
+    }
+}
+
+/// A tracked file is stale if its size changed or it was modified after tracking
+fn is_stale(entry: &ContextEntry, path: &Path) -> bool {
+    let Ok(metadata) = fs::metadata(path) else {
+        return true;
+    };
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    metadata.len() != entry.size || modified > entry.tracked_at
+}
+
+/// Format a directory roll-up with a per-subdirectory breakdown
+fn format_rollup(dir: &Path, rollup: &DirRollup) -> String {
+    if rollup.total.total() == 0 {
+        return format!("{}: no files", dir.display());
+    }
+
+    let mut lines = vec![format!(
+        "{}: {} files - {}",
+        dir.display(),
+        rollup.total.total(),
+        format_counts(&rollup.total)
+    )];
+
+    let width = rollup.groups.keys().map(|k| k.len() + 1).max().unwrap_or(0);
+    for (group, counts) in &rollup.groups {
+        let label = if group == "." {
+            group.clone()
+        } else {
+            format!("{}/", group)
+        };
+        lines.push(format!(
+            "  {:<width$}  {}",
+            label,
+            format_counts(counts),
+            width = width
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Format counts as "N tracked, N untracked, N stale"
+fn format_counts(counts: &RollupCounts) -> String {
+    format!(
+        "{} tracked, {} untracked, {} stale",
+        counts.tracked, counts.untracked, counts.stale
+    )
+}
+
 /// Show summary of all tracked files
-pub async fn summary() -> Result<()> {
+pub async fn summary(sort: SummarySort) -> Result<()> {
     let store = default_store()?;
-    summary_with_store(&store)
+    summary_with_store(&store, sort)
 }
 
 /// Show summary using a specific store (for testing)
-pub fn summary_with_store(store: &impl ContextStore) -> Result<()> {
+pub fn summary_with_store(store: &impl ContextStore, sort: SummarySort) -> Result<()> {
     let state = store.load()?;
     let now = current_timestamp();
 
@@ -76,19 +224,10 @@ pub fn summary_with_store(store: &impl ContextStore) -> Result<()> {
     println!("Session: {}", state.session_id);
     println!();
 
-    let mut entries: Vec<_> = state.all_entries().into_iter().collect();
-    entries.sort_by(|a, b| b.tracked_at.cmp(&a.tracked_at));
-
-    for entry in &entries {
-        let age = format_age(now.saturating_sub(entry.tracked_at));
-        println!(
-            "  {} ({} lines, {}) - {}",
-            entry.path.display(),
-            entry.line_count,
-            format_bytes(entry.size),
-            age
-        );
-    }
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let _span = timings::span("render");
+    let groups = summary::group_entries(&state.all_entries(), &cwd, sort);
+    println!("{}", summary::format_groups(&groups, &cwd, now));
 
     println!();
     println!(
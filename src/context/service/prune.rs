@@ -0,0 +1,130 @@
+use anyhow::Result;
+
+use super::{current_timestamp, BYTES_PER_TOKEN};
+use crate::context::cli::PruneArgs;
+use crate::context::store::{default_store, ContextStore};
+use crate::context::types::{ContextEntry, ContextState};
+
+/// Drop stale or deleted-file entries
+pub async fn prune(args: &PruneArgs) -> Result<()> {
+    let store = default_store()?;
+    prune_with_store(&store, args)
+}
+
+/// Prune using a specific store (for testing)
+pub fn prune_with_store(store: &impl ContextStore, args: &PruneArgs) -> Result<()> {
+    let mut state = store.load()?;
+    let removed = prune_state(&mut state, args, current_timestamp());
+    println!("{}", format_pruned(&removed, state.file_count()));
+    if !removed.is_empty() {
+        store.save(&state)?;
+    }
+    Ok(())
+}
+
+/// Remove entries older than `--older-than` and/or whose file is gone
+fn prune_state(state: &mut ContextState, args: &PruneArgs, now: u64) -> Vec<ContextEntry> {
+    let cutoff = args.older_than.map(|secs| now.saturating_sub(secs));
+    state.remove_where(|e| {
+        cutoff.is_some_and(|c| e.tracked_at < c) || (args.missing && !e.path.exists())
+    })
+}
+
+fn format_pruned(removed: &[ContextEntry], remaining: usize) -> String {
+    if removed.is_empty() {
+        return format!("Nothing to prune ({} files tracked)", remaining);
+    }
+
+    let mut lines: Vec<String> = removed
+        .iter()
+        .map(|e| format!("Pruned: {}", e.path.display()))
+        .collect();
+    let bytes: u64 = removed.iter().map(|e| e.size).sum();
+    lines.push(format!(
+        "Removed {} files (~{} tokens), {} remaining",
+        removed.len(),
+        bytes / BYTES_PER_TOKEN,
+        remaining
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const NOW: u64 = 100_000;
+
+    fn state_with(dir: &std::path::Path) -> ContextState {
+        let present = dir.join("present.rs");
+        std::fs::write(&present, "fn main() {}\n").unwrap();
+
+        let mut state = ContextState::new("s".to_string());
+        state.track(ContextEntry::with_timestamp(present, 40, 1, NOW - 60));
+        state.track(ContextEntry::with_timestamp(
+            dir.join("deleted.rs"),
+            80,
+            2,
+            NOW - 60,
+        ));
+        state.track(ContextEntry::with_timestamp(
+            PathBuf::from("/nonexistent/old.rs"),
+            400,
+            9,
+            NOW - 3 * 3600,
+        ));
+        state
+    }
+
+    fn args(older_than: Option<u64>, missing: bool) -> PruneArgs {
+        PruneArgs {
+            older_than,
+            missing,
+        }
+    }
+
+    #[test]
+    fn prune_older_than() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = state_with(dir.path());
+        let removed = prune_state(&mut state, &args(Some(7200), false), NOW);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].path, PathBuf::from("/nonexistent/old.rs"));
+        assert_eq!(state.file_count(), 2);
+    }
+
+    #[test]
+    fn prune_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = state_with(dir.path());
+        let removed = prune_state(&mut state, &args(None, true), NOW);
+        assert_eq!(removed.len(), 2);
+        assert_eq!(state.file_count(), 1);
+        assert!(state.is_tracked(&dir.path().join("present.rs")));
+    }
+
+    #[test]
+    fn prune_both_criteria() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = state_with(dir.path());
+        let removed = prune_state(&mut state, &args(Some(30), true), NOW);
+        assert_eq!(removed.len(), 3);
+        assert_eq!(state.file_count(), 0);
+    }
+
+    #[test]
+    fn format_pruned_summary() {
+        assert_eq!(format_pruned(&[], 3), "Nothing to prune (3 files tracked)");
+        let removed = vec![ContextEntry::with_timestamp(
+            PathBuf::from("/a.rs"),
+            400,
+            9,
+            1,
+        )];
+        assert_eq!(
+            format_pruned(&removed, 2),
+            "Pruned: /a.rs\nRemoved 1 files (~100 tokens), 2 remaining"
+        );
+    }
+}
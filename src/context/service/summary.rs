@@ -0,0 +1,243 @@
+use std::path::{Component, Path};
+
+use super::{format_age, format_bytes};
+use crate::context::cli::SummarySort;
+use crate::context::types::{ContextEntry, SummaryGroup, TrackSummary};
+
+/// Entries tracked within this many seconds are "hot"
+const HOT_SECS: u64 = 5 * 60;
+/// Entries tracked within this many seconds are "warm"
+const WARM_SECS: u64 = 60 * 60;
+
+/// Group entries by top-level directory (relative to `cwd` when inside it),
+/// sorting entries and groups by `sort`
+pub fn group_entries(
+    entries: &[&ContextEntry],
+    cwd: &Path,
+    sort: SummarySort,
+) -> Vec<SummaryGroup> {
+    let mut groups: Vec<SummaryGroup> = Vec::new();
+
+    for entry in entries {
+        let name = group_name(&entry.path, cwd);
+        let group = match groups.iter().position(|g| g.name == name) {
+            Some(idx) => &mut groups[idx],
+            None => {
+                groups.push(SummaryGroup {
+                    name,
+                    entries: vec![],
+                    totals: TrackSummary::default(),
+                });
+                groups.last_mut().expect("invariant: group was just pushed")
+            }
+        };
+        group.totals.add(entry.size, entry.line_count);
+        group.entries.push((*entry).clone());
+    }
+
+    for group in &mut groups {
+        sort_entries(&mut group.entries, sort);
+    }
+    // Ties fall back to the group name so the output is deterministic
+    match sort {
+        SummarySort::Size => groups.sort_by(|a, b| {
+            b.totals
+                .bytes
+                .cmp(&a.totals.bytes)
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+        SummarySort::Lines => groups.sort_by(|a, b| {
+            b.totals
+                .lines
+                .cmp(&a.totals.lines)
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+        SummarySort::Age => {
+            groups.sort_by(|a, b| newest(b).cmp(&newest(a)).then_with(|| a.name.cmp(&b.name)))
+        }
+    }
+
+    groups
+}
+
+/// Top-level directory of a path relative to `cwd`, "." for files directly
+/// in `cwd`, or the parent directory for paths outside it
+fn group_name(path: &Path, cwd: &Path) -> String {
+    match path.strip_prefix(cwd) {
+        Ok(rel) => match rel.components().next() {
+            Some(Component::Normal(first)) if rel.components().count() > 1 => {
+                format!("{}/", first.to_string_lossy())
+            }
+            _ => ".".to_string(),
+        },
+        Err(_) => path
+            .parent()
+            .map(|p| format!("{}/", p.display()))
+            .unwrap_or_else(|| "/".to_string()),
+    }
+}
+
+fn sort_entries(entries: &mut [ContextEntry], sort: SummarySort) {
+    match sort {
+        SummarySort::Size => entries.sort_by_key(|e| std::cmp::Reverse(e.size)),
+        SummarySort::Lines => entries.sort_by_key(|e| std::cmp::Reverse(e.line_count)),
+        SummarySort::Age => entries.sort_by_key(|e| std::cmp::Reverse(e.tracked_at)),
+    }
+}
+
+fn newest(group: &SummaryGroup) -> u64 {
+    group
+        .entries
+        .iter()
+        .map(|e| e.tracked_at)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Heat indicator for an entry tracked `age_secs` ago
+pub fn heat(age_secs: u64) -> &'static str {
+    if age_secs < HOT_SECS {
+        "●"
+    } else if age_secs < WARM_SECS {
+        "◐"
+    } else {
+        "○"
+    }
+}
+
+/// Format grouped entries with per-group totals and heat indicators
+pub fn format_groups(groups: &[SummaryGroup], cwd: &Path, now: u64) -> String {
+    let mut lines = Vec::new();
+
+    for group in groups {
+        lines.push(format!(
+            "{} ({} {}, {} lines, {})",
+            group.name,
+            group.totals.files,
+            if group.totals.files == 1 {
+                "file"
+            } else {
+                "files"
+            },
+            group.totals.lines,
+            format_bytes(group.totals.bytes)
+        ));
+
+        for entry in &group.entries {
+            let age_secs = now.saturating_sub(entry.tracked_at);
+            let path = entry.path.strip_prefix(cwd).unwrap_or(&entry.path);
+            lines.push(format!(
+                "  {} {} ({} lines, {}) - {}",
+                heat(age_secs),
+                path.display(),
+                entry.line_count,
+                format_bytes(entry.size),
+                format_age(age_secs)
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    lines.push("Heat: ● <5m  ◐ <1h  ○ older".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn entry(path: &str, size: u64, lines: usize, tracked_at: u64) -> ContextEntry {
+        ContextEntry::with_timestamp(PathBuf::from(path), size, lines, tracked_at)
+    }
+
+    fn sample() -> Vec<ContextEntry> {
+        vec![
+            entry("/proj/src/a.rs", 100, 10, 1000),
+            entry("/proj/src/deep/b.rs", 900, 5, 3000),
+            entry("/proj/README.md", 50, 40, 2000),
+            entry("/other/x.rs", 10, 1, 500),
+        ]
+    }
+
+    #[test]
+    fn group_names() {
+        let cwd = Path::new("/proj");
+        assert_eq!(group_name(Path::new("/proj/src/a.rs"), cwd), "src/");
+        assert_eq!(group_name(Path::new("/proj/src/x/b.rs"), cwd), "src/");
+        assert_eq!(group_name(Path::new("/proj/README.md"), cwd), ".");
+        assert_eq!(group_name(Path::new("/other/x.rs"), cwd), "/other/");
+    }
+
+    #[test]
+    fn group_totals() {
+        let entries = sample();
+        let refs: Vec<_> = entries.iter().collect();
+        let groups = group_entries(&refs, Path::new("/proj"), SummarySort::Age);
+        let src = groups.iter().find(|g| g.name == "src/").unwrap();
+        assert_eq!(src.totals.files, 2);
+        assert_eq!(src.totals.lines, 15);
+        assert_eq!(src.totals.bytes, 1000);
+    }
+
+    #[test]
+    fn sort_by_age_newest_first() {
+        let entries = sample();
+        let refs: Vec<_> = entries.iter().collect();
+        let groups = group_entries(&refs, Path::new("/proj"), SummarySort::Age);
+        let names: Vec<_> = groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, vec!["src/", ".", "/other/"]);
+        assert!(groups[0].entries[0].path.ends_with("b.rs"));
+    }
+
+    #[test]
+    fn tied_groups_sort_by_name() {
+        let entries = [
+            entry("/proj/zeta/a.rs", 10, 1, 1000),
+            entry("/proj/alpha/b.rs", 10, 1, 1000),
+        ];
+        let refs: Vec<_> = entries.iter().collect();
+        for sort in [SummarySort::Size, SummarySort::Lines, SummarySort::Age] {
+            let groups = group_entries(&refs, Path::new("/proj"), sort);
+            let names: Vec<_> = groups.iter().map(|g| g.name.as_str()).collect();
+            assert_eq!(names, vec!["alpha/", "zeta/"]);
+        }
+    }
+
+    #[test]
+    fn sort_by_lines() {
+        let entries = sample();
+        let refs: Vec<_> = entries.iter().collect();
+        let groups = group_entries(&refs, Path::new("/proj"), SummarySort::Lines);
+        assert_eq!(groups[0].name, ".");
+        assert!(groups[1].entries[0].path.ends_with("a.rs"));
+    }
+
+    #[test]
+    fn sort_by_size() {
+        let entries = sample();
+        let refs: Vec<_> = entries.iter().collect();
+        let groups = group_entries(&refs, Path::new("/proj"), SummarySort::Size);
+        assert_eq!(groups[0].name, "src/");
+        assert!(groups[0].entries[0].path.ends_with("b.rs"));
+    }
+
+    #[test]
+    fn heat_levels() {
+        assert_eq!(heat(0), "●");
+        assert_eq!(heat(HOT_SECS), "◐");
+        assert_eq!(heat(WARM_SECS), "○");
+    }
+
+    #[test]
+    fn format_groups_output() {
+        let entries = [entry("/proj/src/a.rs", 100, 10, 1000)];
+        let refs: Vec<_> = entries.iter().collect();
+        let groups = group_entries(&refs, Path::new("/proj"), SummarySort::Age);
+        let output = format_groups(&groups, Path::new("/proj"), 1060);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "src/ (1 file, 10 lines, 100 B)");
+        assert_eq!(lines[1], "  ● src/a.rs (10 lines, 100 B) - 1m ago");
+        assert_eq!(lines[3], "Heat: ● <5m  ◐ <1h  ○ older");
+    }
+}
@@ -130,7 +130,7 @@ fn clear_with_store_deletes() {
 fn summary_with_store_empty() {
     let store = MockStore::new();
     // Just verify it doesn't panic
-    summary_with_store(&store).unwrap();
+    summary_with_store(&store, SummarySort::Age).unwrap();
 }
 
 #[test]
@@ -149,7 +149,7 @@ fn summary_with_store_with_entries() {
         2000,
     ));
     let store = MockStore::with_state(state);
-    summary_with_store(&store).unwrap();
+    summary_with_store(&store, SummarySort::Age).unwrap();
 }
 
 #[test]
@@ -257,3 +257,149 @@ fn print_file_status_not_loaded() {
     // Just verify it doesn't panic - output goes to stdout
     print_file_status(&status);
 }
+
+fn track_fixture() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("src/nested")).unwrap();
+    std::fs::create_dir_all(dir.path().join("node_modules")).unwrap();
+    std::fs::write(dir.path().join("src/a.rs"), "fn a() {}\n").unwrap();
+    std::fs::write(dir.path().join("src/nested/b.rs"), "fn b() {}\nfn c() {}\n").unwrap();
+    std::fs::write(dir.path().join("src/notes.md"), "# notes\n").unwrap();
+    std::fs::write(dir.path().join("node_modules/dep.js"), "x\n").unwrap();
+    dir
+}
+
+#[test]
+fn expand_paths_directory() {
+    let dir = track_fixture();
+    let files = expand_paths(&[dir.path().to_string_lossy().to_string()]).unwrap();
+    assert_eq!(files.len(), 3);
+    assert!(!files.iter().any(|p| p.ends_with("dep.js")));
+}
+
+#[test]
+fn expand_paths_glob() {
+    let dir = track_fixture();
+    let pattern = format!("{}/src/**/*.rs", dir.path().display());
+    let files = expand_paths(&[pattern]).unwrap();
+    assert_eq!(files.len(), 2);
+    assert!(files.iter().all(|p| p.is_absolute()));
+}
+
+#[test]
+fn expand_paths_glob_no_match() {
+    let dir = track_fixture();
+    let pattern = format!("{}/src/*.py", dir.path().display());
+    assert!(expand_paths(&[pattern]).is_err());
+}
+
+#[test]
+fn expand_paths_dedupes_overlap() {
+    let dir = track_fixture();
+    let src = dir.path().join("src");
+    let file = src.join("a.rs");
+    let files = expand_paths(&[
+        file.to_string_lossy().to_string(),
+        src.to_string_lossy().to_string(),
+    ])
+    .unwrap();
+    assert_eq!(files.len(), 3);
+}
+
+#[test]
+fn track_with_store_directory() {
+    let dir = track_fixture();
+    let store = MockStore::new();
+    track_with_store(&store, &[dir.path().to_string_lossy().to_string()]).unwrap();
+
+    let state = store.load().unwrap();
+    assert_eq!(state.file_count(), 3);
+    assert_eq!(state.total_lines(), 4);
+}
+
+#[test]
+fn format_track_summary_estimates_tokens() {
+    let summary = TrackSummary {
+        files: 3,
+        lines: 40,
+        bytes: 400,
+    };
+    assert_eq!(
+        format_track_summary(&summary),
+        "Added: 3 files, 40 lines, ~100 tokens"
+    );
+}
+
+#[test]
+fn rollup_dir_classifies_files() {
+    let dir = track_fixture();
+    let root = resolve_path(&dir.path().to_string_lossy()).unwrap();
+    let a = root.join("src/a.rs");
+    let b = root.join("src/nested/b.rs");
+
+    let mut state = ContextState::new("test".to_string());
+    let (size, lines) = get_file_info(&a).unwrap();
+    state.track(ContextEntry::with_timestamp(a, size, lines, u64::MAX));
+    state.track(ContextEntry::with_timestamp(b, 1, 1, u64::MAX));
+
+    let rollup = rollup_dir(&state, &root).unwrap();
+    assert_eq!(rollup.total.tracked, 1);
+    assert_eq!(rollup.total.stale, 1);
+    assert_eq!(rollup.total.untracked, 1);
+    assert_eq!(rollup.groups.len(), 1);
+    assert_eq!(rollup.groups["src"].total(), 3);
+}
+
+#[test]
+fn rollup_dir_groups_root_files() {
+    let dir = track_fixture();
+    std::fs::write(dir.path().join("README.md"), "readme\n").unwrap();
+    let state = ContextState::new("test".to_string());
+
+    let rollup = rollup_dir(&state, dir.path()).unwrap();
+    assert_eq!(rollup.groups["."].untracked, 1);
+    assert_eq!(rollup.groups["src"].untracked, 3);
+}
+
+#[test]
+fn is_stale_when_modified_after_tracking() {
+    let dir = track_fixture();
+    let path = dir.path().join("src/a.rs");
+    let (size, lines) = get_file_info(&path).unwrap();
+
+    let old = ContextEntry::with_timestamp(path.clone(), size, lines, 0);
+    assert!(is_stale(&old, &path));
+    let fresh = ContextEntry::with_timestamp(path.clone(), size, lines, u64::MAX);
+    assert!(!is_stale(&fresh, &path));
+}
+
+#[test]
+fn format_rollup_breakdown() {
+    let mut rollup = DirRollup::default();
+    rollup.add("src", RollupState::Tracked);
+    rollup.add("src", RollupState::Stale);
+    rollup.add(".", RollupState::Untracked);
+
+    let output = format_rollup(Path::new("/proj"), &rollup);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "/proj: 3 files - 1 tracked, 1 untracked, 1 stale");
+    assert_eq!(lines[1], "  .     0 tracked, 1 untracked, 0 stale");
+    assert_eq!(lines[2], "  src/  1 tracked, 0 untracked, 1 stale");
+}
+
+#[test]
+fn format_rollup_empty_dir() {
+    let rollup = DirRollup::default();
+    assert_eq!(
+        format_rollup(Path::new("/empty"), &rollup),
+        "/empty: no files"
+    );
+}
+
+#[test]
+fn check_with_store_directory() {
+    let dir = track_fixture();
+    let store = MockStore::new();
+    let result = check_with_store(&store, &[dir.path().to_string_lossy().to_string()]);
+    assert!(result.is_ok());
+}
@@ -2,8 +2,11 @@ use anyhow::{Context, Result};
 use std::env;
 use std::fs;
 use std::path::PathBuf;
+use std::time::SystemTime;
 
+use super::config;
 use super::types::ContextState;
+use crate::util::timings;
 
 /// Trait for context storage (enables mocking in tests)
 pub trait ContextStore {
@@ -16,6 +19,8 @@ pub trait ContextStore {
 pub struct FileContextStore {
     path: PathBuf,
     session_id: String,
+    /// Entries older than this many seconds are dropped on load
+    expire_after: Option<u64>,
 }
 
 impl FileContextStore {
@@ -23,18 +28,28 @@ impl FileContextStore {
     pub fn new() -> Result<Self> {
         let session_id = get_session_id();
         let path = context_file_path(&session_id);
-        Ok(Self { path, session_id })
+        let expire_after = config::load_expire_after();
+        Ok(Self {
+            path,
+            session_id,
+            expire_after,
+        })
     }
 
     /// Create with explicit path (for testing)
     #[cfg(test)]
     pub fn with_path(path: PathBuf, session_id: String) -> Self {
-        Self { path, session_id }
+        Self {
+            path,
+            session_id,
+            expire_after: None,
+        }
     }
 }
 
 impl ContextStore for FileContextStore {
     fn load(&self) -> Result<ContextState> {
+        let _span = timings::span("io: context load");
         if !self.path.exists() {
             return Ok(ContextState::new(self.session_id.clone()));
         }
@@ -42,11 +57,20 @@ impl ContextStore for FileContextStore {
         let contents = fs::read_to_string(&self.path)
             .with_context(|| format!("Failed to read {}", self.path.display()))?;
 
-        serde_json::from_str(&contents)
-            .with_context(|| format!("Failed to parse {}", self.path.display()))
+        let mut state: ContextState = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", self.path.display()))?;
+        if let Some(secs) = self.expire_after {
+            let now = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            state.expire_before(now.saturating_sub(secs));
+        }
+        Ok(state)
     }
 
     fn save(&self, state: &ContextState) -> Result<()> {
+        let _span = timings::span("io: context save");
         if let Some(dir) = self.path.parent() {
             fs::create_dir_all(dir)
                 .with_context(|| format!("Failed to create directory {}", dir.display()))?;
@@ -161,6 +185,28 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp_dir);
     }
 
+    #[test]
+    fn store_load_expires_old_entries() {
+        let (mut store, tmp_dir) = temp_store();
+        let mut state = ContextState::new("test-session".to_string());
+        state.track(ContextEntry::with_timestamp(
+            PathBuf::from("/old.rs"),
+            1,
+            1,
+            1,
+        ));
+        state.track(ContextEntry::new(PathBuf::from("/new.rs"), 1, 1));
+        store.save(&state).unwrap();
+
+        assert_eq!(store.load().unwrap().file_count(), 2);
+        store.expire_after = Some(3600);
+        let loaded = store.load().unwrap();
+        assert_eq!(loaded.file_count(), 1);
+        assert!(loaded.is_tracked(&PathBuf::from("/new.rs")));
+
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+    }
+
     #[test]
     fn store_delete() {
         let (store, tmp_dir) = temp_store();
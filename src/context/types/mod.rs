@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+#[cfg(test)]
+mod tests;
+
+/// Entry for a tracked file in the context
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContextEntry {
+    /// Absolute path to the file
+    pub path: PathBuf,
+    /// File size in bytes
+    pub size: u64,
+    /// Number of lines in the file
+    pub line_count: usize,
+    /// When the file was tracked (Unix timestamp)
+    pub tracked_at: u64,
+}
+
+impl ContextEntry {
+    /// Create a new context entry from file metadata
+    pub fn new(path: PathBuf, size: u64, line_count: usize) -> Self {
+        let tracked_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Self {
+            path,
+            size,
+            line_count,
+            tracked_at,
+        }
+    }
+
+    /// Create entry with explicit timestamp (for testing)
+    #[cfg(test)]
+    pub fn with_timestamp(path: PathBuf, size: u64, line_count: usize, tracked_at: u64) -> Self {
+        Self {
+            path,
+            size,
+            line_count,
+            tracked_at,
+        }
+    }
+}
+
+/// Complete context state for a session
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ContextState {
+    /// Session ID this context belongs to
+    pub session_id: String,
+    /// Map of canonical path string to entry
+    pub entries: HashMap<String, ContextEntry>,
+}
+
+impl ContextState {
+    /// Create a new empty context state
+    pub fn new(session_id: String) -> Self {
+        Self {
+            session_id,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Add or update an entry
+    pub fn track(&mut self, entry: ContextEntry) {
+        let key = entry.path.to_string_lossy().to_string();
+        self.entries.insert(key, entry);
+    }
+
+    /// Get an entry by path
+    pub fn get(&self, path: &Path) -> Option<&ContextEntry> {
+        let key = path.to_string_lossy().to_string();
+        self.entries.get(&key)
+    }
+
+    /// Check if a path is tracked
+    #[cfg(test)]
+    pub fn is_tracked(&self, path: &Path) -> bool {
+        let key = path.to_string_lossy().to_string();
+        self.entries.contains_key(&key)
+    }
+
+    /// Get all entries
+    pub fn all_entries(&self) -> Vec<&ContextEntry> {
+        self.entries.values().collect()
+    }
+
+    /// Clear all entries
+    #[cfg(test)]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Remove entries matching `pred`, returning them sorted by path
+    pub fn remove_where(&mut self, pred: impl Fn(&ContextEntry) -> bool) -> Vec<ContextEntry> {
+        let keys: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, e)| pred(e))
+            .map(|(k, _)| k.clone())
+            .collect();
+        let mut removed: Vec<ContextEntry> =
+            keys.iter().filter_map(|k| self.entries.remove(k)).collect();
+        removed.sort_by(|a, b| a.path.cmp(&b.path));
+        removed
+    }
+
+    /// Remove entries tracked before `cutoff` (Unix timestamp)
+    pub fn expire_before(&mut self, cutoff: u64) -> Vec<ContextEntry> {
+        self.remove_where(|e| e.tracked_at < cutoff)
+    }
+
+    /// Total tracked files
+    pub fn file_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Total bytes tracked
+    pub fn total_bytes(&self) -> u64 {
+        self.entries.values().map(|e| e.size).sum()
+    }
+
+    /// Total lines tracked
+    pub fn total_lines(&self) -> usize {
+        self.entries.values().map(|e| e.line_count).sum()
+    }
+}
+
+/// Result of checking a file's status
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileStatus {
+    /// File is already in context
+    Loaded { entry: ContextEntry, age_secs: u64 },
+    /// File is not in context
+    NotLoaded {
+        path: PathBuf,
+        size: u64,
+        line_count: usize,
+    },
+}
+
+/// Totals for a set of tracked files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrackSummary {
+    pub files: usize,
+    pub lines: usize,
+    pub bytes: u64,
+}
+
+impl TrackSummary {
+    /// Count one tracked file
+    pub fn add(&mut self, bytes: u64, lines: usize) {
+        self.files += 1;
+        self.lines += lines;
+        self.bytes += bytes;
+    }
+}
+
+/// Tracked entries under one top-level directory, with totals
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryGroup {
+    /// Directory label, e.g. "src/" ("." for files in the working directory)
+    pub name: String,
+    pub entries: Vec<ContextEntry>,
+    pub totals: TrackSummary,
+}
+
+/// Context state of a file found under a checked directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollupState {
+    /// Tracked and unchanged since
+    Tracked,
+    /// Not in context
+    Untracked,
+    /// Tracked, but modified after it was tracked
+    Stale,
+}
+
+/// Tracked/untracked/stale counts for a group of files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RollupCounts {
+    pub tracked: usize,
+    pub untracked: usize,
+    pub stale: usize,
+}
+
+impl RollupCounts {
+    /// Count one file
+    pub fn add(&mut self, state: RollupState) {
+        match state {
+            RollupState::Tracked => self.tracked += 1,
+            RollupState::Untracked => self.untracked += 1,
+            RollupState::Stale => self.stale += 1,
+        }
+    }
+
+    /// Total files counted
+    pub fn total(&self) -> usize {
+        self.tracked + self.untracked + self.stale
+    }
+}
+
+/// Aggregate status for a directory, broken down by top-level subdirectory
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DirRollup {
+    pub total: RollupCounts,
+    /// Keyed by subdirectory name; files directly in the directory use "."
+    pub groups: BTreeMap<String, RollupCounts>,
+}
+
+impl DirRollup {
+    /// Count one file in the given group
+    pub fn add(&mut self, group: &str, state: RollupState) {
+        self.total.add(state);
+        self.groups.entry(group.to_string()).or_default().add(state);
+    }
+}
@@ -8,6 +8,10 @@ pub enum CronCommand {
     List(ListArgs),
     /// Remove a cron job
     Remove(RemoveArgs),
+    /// Export hu-managed jobs as TOML
+    Export(ExportArgs),
+    /// Import hu-managed jobs from a TOML export
+    Import(ImportArgs),
 }
 
 #[derive(Debug, Args)]
@@ -43,6 +47,25 @@ pub struct RemoveArgs {
     pub json: bool,
 }
 
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// Write to file instead of stdout
+    #[arg(long, short)]
+    pub output: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    /// TOML file produced by `hu cron export`
+    pub file: String,
+    /// Show what would change without writing the crontab
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Output as JSON
+    #[arg(long, short)]
+    pub json: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +159,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_export() {
+        let cli = TestCli::try_parse_from(["test", "export", "-o", "jobs.toml"]).unwrap();
+        match cli.cmd {
+            CronCommand::Export(args) => {
+                assert_eq!(args.output.as_deref(), Some("jobs.toml"));
+            }
+            _ => panic!("expected Export"),
+        }
+    }
+
+    #[test]
+    fn parse_import_dry_run() {
+        let cli = TestCli::try_parse_from(["test", "import", "jobs.toml", "--dry-run"]).unwrap();
+        match cli.cmd {
+            CronCommand::Import(args) => {
+                assert_eq!(args.file, "jobs.toml");
+                assert!(args.dry_run);
+                assert!(!args.json);
+            }
+            _ => panic!("expected Import"),
+        }
+    }
+
     #[test]
     fn add_args_debug() {
         let args = AddArgs {
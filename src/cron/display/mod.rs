@@ -1,5 +1,6 @@
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, Color, ContentArrangement, Table};
 
+use super::transfer::ImportPlan;
 use super::types::CronJob;
 
 #[cfg(test)]
@@ -93,6 +94,46 @@ pub fn format_removed(jobs: &[CronJob], json: bool) -> String {
     output.trim_end().to_string()
 }
 
+/// Format the outcome (or, with `dry_run`, the preview) of an import
+pub fn format_import_plan(plan: &ImportPlan, dry_run: bool, json: bool) -> String {
+    if json {
+        return serde_json::to_string_pretty(plan).unwrap_or_else(|_| "{}".to_string());
+    }
+
+    let verb = if dry_run { "Would add" } else { "Added" };
+    let mut lines = Vec::new();
+    if plan.conflicts.is_empty() || dry_run {
+        for job in &plan.add {
+            lines.push(format!(
+                "\x1b[32m+\x1b[0m {} {} {}",
+                verb,
+                job.expression,
+                truncate_command(&job.command, 50)
+            ));
+        }
+    }
+    for job in &plan.unchanged {
+        lines.push(format!(
+            "  Unchanged {} {}",
+            job.expression,
+            truncate_command(&job.command, 50)
+        ));
+    }
+    for conflict in &plan.conflicts {
+        lines.push(format!(
+            "\x1b[31m!\x1b[0m Conflict {} {} (existing: {})",
+            conflict.job.expression,
+            truncate_command(&conflict.job.command, 50),
+            conflict.existing.expression
+        ));
+    }
+
+    if lines.is_empty() {
+        return "No jobs to import".to_string();
+    }
+    lines.join("\n")
+}
+
 /// Truncate a command string for display
 fn truncate_command(cmd: &str, max_len: usize) -> String {
     if cmd.len() <= max_len {
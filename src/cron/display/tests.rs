@@ -1,4 +1,5 @@
 use super::*;
+use crate::cron::transfer::{Conflict, ExportedJob};
 
 #[test]
 fn format_jobs_empty() {
@@ -214,3 +215,51 @@ fn format_jobs_long_command_truncated() {
     let output = format_jobs(&jobs, false);
     assert!(output.contains("..."));
 }
+
+fn exported(expression: &str, command: &str) -> ExportedJob {
+    ExportedJob {
+        schedule: "daily".to_string(),
+        expression: expression.to_string(),
+        command: command.to_string(),
+    }
+}
+
+#[test]
+fn format_import_plan_dry_run() {
+    let plan = ImportPlan {
+        add: vec![exported("1 2 * * *", "hu docs sync")],
+        unchanged: vec![exported("3 4 * * *", "hu data sync")],
+        conflicts: vec![],
+    };
+    let output = format_import_plan(&plan, true, false);
+    assert!(output.contains("Would add 1 2 * * * hu docs sync"));
+    assert!(output.contains("Unchanged 3 4 * * * hu data sync"));
+}
+
+#[test]
+fn format_import_plan_conflicts_hide_additions() {
+    let plan = ImportPlan {
+        add: vec![exported("1 2 * * *", "hu docs sync")],
+        unchanged: vec![],
+        conflicts: vec![Conflict {
+            job: exported("0 7 * * *", "hu x"),
+            existing: CronJob {
+                expression: "0 8 * * *".to_string(),
+                command: "hu x".to_string(),
+                schedule_name: None,
+                is_hu_job: false,
+            },
+        }],
+    };
+    let output = format_import_plan(&plan, false, false);
+    assert!(!output.contains("Added"));
+    assert!(output.contains("Conflict 0 7 * * * hu x (existing: 0 8 * * *)"));
+}
+
+#[test]
+fn format_import_plan_empty_and_json() {
+    let plan = ImportPlan::default();
+    assert_eq!(format_import_plan(&plan, false, false), "No jobs to import");
+    let json = format_import_plan(&plan, false, true);
+    assert!(json.contains("\"conflicts\": []"));
+}
@@ -1,13 +1,14 @@
 mod cli;
 mod display;
 mod service;
+mod transfer;
 mod types;
 
 pub use cli::CronCommand;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
-use cli::{AddArgs, ListArgs, RemoveArgs};
+use cli::{AddArgs, ExportArgs, ImportArgs, ListArgs, RemoveArgs};
 use types::Schedule;
 
 /// Run a cron subcommand
@@ -16,6 +17,8 @@ pub fn run_command(cmd: CronCommand) -> Result<()> {
         CronCommand::Add(args) => run_add(args),
         CronCommand::List(args) => run_list(args),
         CronCommand::Remove(args) => run_remove(args),
+        CronCommand::Export(args) => run_export(args),
+        CronCommand::Import(args) => run_import(args),
     }
 }
 
@@ -61,6 +64,39 @@ fn run_remove(args: RemoveArgs) -> Result<()> {
     Ok(())
 }
 
+fn run_export(args: ExportArgs) -> Result<()> {
+    let jobs = service::list_jobs(true)?;
+    let toml = transfer::export_jobs(&jobs)?;
+
+    match args.output {
+        Some(path) => {
+            std::fs::write(&path, toml).with_context(|| format!("Failed to write {}", path))?;
+            eprintln!("Exported {} job(s) to {}", jobs.len(), path);
+        }
+        None => print!("{}", toml),
+    }
+    Ok(())
+}
+
+fn run_import(args: ImportArgs) -> Result<()> {
+    let content = std::fs::read_to_string(&args.file)
+        .with_context(|| format!("Failed to read {}", args.file))?;
+    let set = transfer::parse_job_set(&content)?;
+    let plan = transfer::plan_import(set, &service::list_jobs(false)?);
+
+    if args.dry_run {
+        println!("{}", display::format_import_plan(&plan, true, args.json));
+        return Ok(());
+    }
+
+    let applied = transfer::apply_import(&plan);
+    // Conflicts are shown even though nothing was written
+    if applied.is_ok() || !plan.conflicts.is_empty() {
+        println!("{}", display::format_import_plan(&plan, false, args.json));
+    }
+    applied
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,242 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::service::{read_crontab, write_crontab};
+use super::types::{CronJob, Schedule, HU_MARKER};
+
+/// A versionable set of hu-managed jobs (`[[job]]` tables in TOML)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct JobSet {
+    #[serde(rename = "job", default)]
+    pub jobs: Vec<ExportedJob>,
+}
+
+/// A hu-managed job as stored in an export file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedJob {
+    /// Schedule name written to the hu marker (hourly, daily, ...)
+    pub schedule: String,
+    /// Exact cron expression, so jobs keep their time across machines
+    pub expression: String,
+    /// Command to run
+    pub command: String,
+}
+
+/// An imported job whose command is already scheduled differently
+#[derive(Debug, Clone, Serialize)]
+pub struct Conflict {
+    pub job: ExportedJob,
+    pub existing: CronJob,
+}
+
+/// What importing a job set would change
+#[derive(Debug, Default, Serialize)]
+pub struct ImportPlan {
+    /// Jobs to append to the crontab
+    pub add: Vec<ExportedJob>,
+    /// Jobs already present with the same expression
+    pub unchanged: Vec<ExportedJob>,
+    /// Jobs whose command exists with a different expression
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Serialize the hu-managed jobs among `jobs` as TOML
+pub fn export_jobs(jobs: &[CronJob]) -> Result<String> {
+    let set = JobSet {
+        jobs: jobs
+            .iter()
+            .filter(|j| j.is_hu_job)
+            .map(|j| ExportedJob {
+                schedule: j.schedule_name.clone().unwrap_or_default(),
+                expression: j.expression.clone(),
+                command: j.command.clone(),
+            })
+            .collect(),
+    };
+    toml::to_string_pretty(&set).context("Failed to serialize cron jobs")
+}
+
+/// Parse and validate an exported job set
+pub fn parse_job_set(content: &str) -> Result<JobSet> {
+    let set: JobSet = toml::from_str(content).context("Invalid job file")?;
+
+    for (i, job) in set.jobs.iter().enumerate() {
+        if Schedule::parse(&job.schedule).is_none() {
+            bail!(
+                "Job {}: invalid schedule '{}'. Use: hourly, daily, weekly, monthly, reboot",
+                i + 1,
+                job.schedule
+            );
+        }
+        let fields = job.expression.split_whitespace().count();
+        if job.expression != "@reboot" && fields != 5 {
+            bail!(
+                "Job {}: invalid cron expression '{}'",
+                i + 1,
+                job.expression
+            );
+        }
+        if job.command.trim().is_empty() {
+            bail!("Job {}: command is empty", i + 1);
+        }
+    }
+
+    Ok(set)
+}
+
+/// Compare a job set against the existing crontab entries
+pub fn plan_import(set: JobSet, existing: &[CronJob]) -> ImportPlan {
+    let mut plan = ImportPlan::default();
+
+    for job in set.jobs {
+        let same_command = existing.iter().find(|e| e.command == job.command);
+        let earlier = plan.add.iter().find(|a| a.command == job.command);
+        match (same_command, earlier) {
+            (Some(e), _) if e.expression == job.expression => plan.unchanged.push(job),
+            (Some(e), _) => plan.conflicts.push(Conflict {
+                job,
+                existing: e.clone(),
+            }),
+            // Exact duplicate within the file
+            (None, Some(a)) if a.expression == job.expression => {}
+            // Same command listed twice with different schedules
+            (None, Some(a)) => {
+                let existing = CronJob {
+                    expression: a.expression.clone(),
+                    command: a.command.clone(),
+                    schedule_name: Some(a.schedule.clone()),
+                    is_hu_job: true,
+                };
+                plan.conflicts.push(Conflict { job, existing });
+            }
+            (None, None) => plan.add.push(job),
+        }
+    }
+
+    plan
+}
+
+/// Append the planned jobs to the crontab (refuses if there are conflicts)
+pub fn apply_import(plan: &ImportPlan) -> Result<()> {
+    if !plan.conflicts.is_empty() {
+        bail!(
+            "{} job(s) conflict with existing entries or with each other; remove them \
+             first (hu cron remove) or edit the job file",
+            plan.conflicts.len()
+        );
+    }
+    if plan.add.is_empty() {
+        return Ok(());
+    }
+
+    let mut crontab = read_crontab()?;
+    crontab.push_str(&render_jobs(&crontab, &plan.add));
+    write_crontab(&crontab)
+}
+
+/// Crontab lines (marker + entry) for `jobs`, appended after `existing`
+fn render_jobs(existing: &str, jobs: &[ExportedJob]) -> String {
+    let mut out = String::new();
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        out.push('\n');
+    }
+    for job in jobs {
+        out.push_str(&format!("{} {}\n", HU_MARKER, job.schedule));
+        out.push_str(&format!("{} {}\n", job.expression, job.command));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cron::service::parse_crontab;
+
+    const CRONTAB: &str = "0 * * * * other\n# hu: daily\n35 18 * * * hu docs sync\n\
+                           # hu: weekly\n0 9 * * 1 hu data sync\n";
+
+    fn job(schedule: &str, expression: &str, command: &str) -> ExportedJob {
+        ExportedJob {
+            schedule: schedule.to_string(),
+            expression: expression.to_string(),
+            command: command.to_string(),
+        }
+    }
+
+    #[test]
+    fn export_only_hu_jobs() {
+        let toml = export_jobs(&parse_crontab(CRONTAB)).unwrap();
+        let set = parse_job_set(&toml).unwrap();
+        assert_eq!(
+            set.jobs,
+            vec![
+                job("daily", "35 18 * * *", "hu docs sync"),
+                job("weekly", "0 9 * * 1", "hu data sync"),
+            ]
+        );
+        assert!(toml.contains("[[job]]"));
+    }
+
+    #[test]
+    fn export_empty_round_trips() {
+        let toml = export_jobs(&[]).unwrap();
+        assert!(parse_job_set(&toml).unwrap().jobs.is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_invalid_jobs() {
+        let bad_schedule =
+            "[[job]]\nschedule = \"often\"\nexpression = \"@reboot\"\ncommand = \"x\"";
+        assert!(parse_job_set(bad_schedule).is_err());
+        let bad_expr = "[[job]]\nschedule = \"daily\"\nexpression = \"* *\"\ncommand = \"x\"";
+        assert!(parse_job_set(bad_expr).is_err());
+        let empty_cmd = "[[job]]\nschedule = \"reboot\"\nexpression = \"@reboot\"\ncommand = \" \"";
+        assert!(parse_job_set(empty_cmd).is_err());
+        assert!(parse_job_set("not toml [").is_err());
+    }
+
+    #[test]
+    fn plan_detects_unchanged_conflicts_and_new() {
+        let set = JobSet {
+            jobs: vec![
+                job("daily", "35 18 * * *", "hu docs sync"),
+                job("daily", "0 7 * * *", "hu data sync"),
+                job("hourly", "5 * * * *", "hu context clear"),
+                job("hourly", "5 * * * *", "hu context clear"),
+                job("hourly", "10 * * * *", "hu context clear"),
+            ],
+        };
+        let plan = plan_import(set, &parse_crontab(CRONTAB));
+        assert_eq!(plan.unchanged.len(), 1);
+        assert_eq!(plan.conflicts.len(), 2);
+        assert_eq!(plan.conflicts[0].existing.expression, "0 9 * * 1");
+        // Same command with a different schedule later in the file
+        assert_eq!(plan.conflicts[1].job.expression, "10 * * * *");
+        assert_eq!(plan.conflicts[1].existing.expression, "5 * * * *");
+        assert_eq!(
+            plan.add,
+            vec![job("hourly", "5 * * * *", "hu context clear")]
+        );
+    }
+
+    #[test]
+    fn apply_refuses_conflicts() {
+        let plan = ImportPlan {
+            conflicts: vec![Conflict {
+                job: job("daily", "0 7 * * *", "x"),
+                existing: parse_crontab("0 8 * * * x").remove(0),
+            }],
+            ..Default::default()
+        };
+        assert!(apply_import(&plan).is_err());
+    }
+
+    #[test]
+    fn render_jobs_adds_markers() {
+        let rendered = render_jobs("0 * * * * other", &[job("daily", "1 2 * * *", "hu x")]);
+        assert_eq!(rendered, "\n# hu: daily\n1 2 * * * hu x\n");
+        let reparsed = parse_crontab(&format!("0 * * * * other{}", rendered));
+        assert!(reparsed[1].is_hu_job);
+        assert_eq!(reparsed[1].schedule_name.as_deref(), Some("daily"));
+    }
+}
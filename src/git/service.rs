@@ -75,16 +75,8 @@ pub fn parse_status_output(output: &str) -> Result<GitStatus> {
 
         // Handle worktree changes (second column)
         match worktree {
-            'M' => {
-                if !status.staged.contains(&path) {
-                    status.modified.push(path);
-                }
-            }
-            'D' => {
-                if !status.deleted.contains(&path) {
-                    status.deleted.push(path);
-                }
-            }
+            'M' if !status.staged.contains(&path) => status.modified.push(path),
+            'D' if !status.deleted.contains(&path) => status.deleted.push(path),
             '?' => status.untracked.push(path),
             _ => {}
         }
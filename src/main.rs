@@ -1,4 +1,5 @@
 use clap::{CommandFactory, Parser};
+use std::time::Instant;
 
 mod cli;
 mod context;
@@ -16,19 +17,29 @@ mod util;
 mod utils;
 
 use cli::{Cli, Command};
+use util::timings;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    if cli.timings {
+        timings::enable();
+    }
+    let started = Instant::now();
 
-    match cli.command {
+    let result = match cli.command {
         Some(cmd) => run_command(cmd).await,
         None => {
             Cli::command().print_help()?;
             println!();
             Ok(())
         }
+    };
+
+    if cli.timings {
+        eprintln!("{}", timings::report(started.elapsed()));
     }
+    result
 }
 
 async fn run_command(cmd: Command) -> anyhow::Result<()> {
@@ -128,6 +139,14 @@ mod tests {
         assert!(matches!(cli.command, Some(Command::NewRelic { cmd: None })));
     }
 
+    #[test]
+    fn parses_global_timings_flag() {
+        let cli = Cli::try_parse_from(["hu", "read", "--timings", "file.rs"]).unwrap();
+        assert!(cli.timings);
+        let cli = Cli::try_parse_from(["hu", "read", "file.rs"]).unwrap();
+        assert!(!cli.timings);
+    }
+
     #[test]
     fn parses_command_aliases() {
         // nr -> newrelic
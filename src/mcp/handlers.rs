@@ -1,8 +1,10 @@
 use anyhow::Result;
+use clap::ValueEnum;
 
 use super::types::ToolResult;
 use crate::data;
 use crate::read;
+use crate::util::redact;
 
 /// Dispatch a tool call to the appropriate handler.
 ///
@@ -121,6 +123,10 @@ fn handle_read_file(args: &serde_json::Value) -> Result<ToolResult> {
             .get("outline")
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
+        metrics: args
+            .get("metrics")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
         interface: args
             .get("interface")
             .and_then(|v| v.as_bool())
@@ -136,10 +142,28 @@ fn handle_read_file(args: &serde_json::Value) -> Result<ToolResult> {
             .and_then(|v| v.as_str())
             .unwrap_or("HEAD")
             .to_string(),
+        follow_imports: args
+            .get("follow_imports")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize),
+        encoding: args
+            .get("encoding")
+            .and_then(|v| v.as_str())
+            .and_then(|s| read::Encoding::from_str(s, true).ok()),
+        format: read::ReadFormat::Text,
+        redact: false,
     };
+    let redact = args
+        .get("redact")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+        || redact::enabled_by_default();
 
     let output = read::read(read_args)?;
-    let text = format!("{output:?}");
+    let mut text = format!("{output:?}");
+    if redact {
+        text = redact::redact(&text).0;
+    }
     Ok(ToolResult::text(text))
 }
 
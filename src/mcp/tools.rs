@@ -120,6 +120,10 @@ fn read_file() -> ToolDef {
                     "type": "boolean",
                     "description": "Show file outline (functions, structs, classes)"
                 },
+                "metrics": {
+                    "type": "boolean",
+                    "description": "Include per-item lines, complexity and TODO counts in the outline"
+                },
                 "interface": {
                     "type": "boolean",
                     "description": "Show public interface only"
@@ -139,6 +143,18 @@ fn read_file() -> ToolDef {
                 "commit": {
                     "type": "string",
                     "description": "Commit to diff against (default: HEAD)"
+                },
+                "follow_imports": {
+                    "type": "integer",
+                    "description": "Append public interfaces of imported files, this many levels deep"
+                },
+                "encoding": {
+                    "type": "string",
+                    "description": "Source encoding: utf-8, utf-16le, utf-16be, latin-1 (default: detect)"
+                },
+                "redact": {
+                    "type": "boolean",
+                    "description": "Replace secrets (keys, tokens, passwords) with placeholders (always on when [redact] enabled = true)"
                 }
             },
             "required": ["path"]
@@ -258,11 +274,15 @@ mod tests {
         for key in &[
             "path",
             "outline",
+            "metrics",
             "interface",
             "around",
             "context",
             "diff",
             "commit",
+            "follow_imports",
+            "encoding",
+            "redact",
         ] {
             assert!(
                 props.get(key).is_some(),
@@ -9,6 +9,7 @@ use tokio::time::sleep;
 
 use super::config::{load_config, NewRelicConfig};
 use super::types::{Incident, Issue};
+use crate::util::timings;
 
 #[cfg(test)]
 mod tests;
@@ -281,6 +282,7 @@ impl NewRelicClient {
         let mut retries = 0;
 
         loop {
+            let api_span = timings::span("api: nerdgraph");
             let response = self
                 .http
                 .post(NERDGRAPH_URL)
@@ -324,6 +326,9 @@ impl NewRelicClient {
             }
 
             let text = response.text().await?;
+            drop(api_span);
+
+            let _parse_span = timings::span("parse: nerdgraph");
             let gql_response: GraphQLResponse<T> = serde_json::from_str(&text).map_err(|e| {
                 anyhow::anyhow!("Parse error: {}: {}", e, &text[..text.len().min(200)])
             })?;
@@ -3,7 +3,8 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+
+use crate::util::{load_settings_section, settings_path, timings};
 
 /// New Relic configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -22,32 +23,12 @@ impl NewRelicConfig {
     }
 }
 
-/// Settings file structure
-#[derive(Debug, Default, Deserialize)]
-struct SettingsFile {
-    newrelic: Option<NewRelicConfig>,
-}
-
-/// Get path to config file
-pub fn config_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|p| p.join(".config").join("hu").join("settings.toml"))
-}
-
 /// Load config from settings file and environment
 #[cfg(not(tarpaulin_include))]
 pub fn load_config() -> Result<NewRelicConfig> {
-    let mut config = NewRelicConfig::default();
-
+    let _span = timings::span("auth: newrelic config");
     // Load from settings file
-    if let Some(path) = config_path() {
-        if path.exists() {
-            let contents = fs::read_to_string(&path)?;
-            let settings: SettingsFile = toml::from_str(&contents)?;
-            if let Some(nr) = settings.newrelic {
-                config = nr;
-            }
-        }
-    }
+    let mut config: NewRelicConfig = load_settings_section("newrelic")?.unwrap_or_default();
 
     // Override with environment variables
     if let Ok(key) = std::env::var("NEW_RELIC_API_KEY") {
@@ -65,7 +46,8 @@ pub fn load_config() -> Result<NewRelicConfig> {
 /// Save config to file
 #[cfg(not(tarpaulin_include))]
 pub fn save_config(api_key: &str, account_id: i64) -> Result<()> {
-    let path = config_path().ok_or_else(|| anyhow::anyhow!("Cannot determine config directory"))?;
+    let path =
+        settings_path().ok_or_else(|| anyhow::anyhow!("Cannot determine config directory"))?;
 
     let contents = if path.exists() {
         fs::read_to_string(&path)?
@@ -111,6 +93,7 @@ pub fn save_config(api_key: &str, account_id: i64) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::settings_section_from_toml;
 
     #[test]
     fn test_newrelic_config_is_configured_both_set() {
@@ -156,14 +139,6 @@ mod tests {
         assert!(!config.is_configured());
     }
 
-    #[test]
-    fn test_config_path_returns_some() {
-        let path = config_path();
-        if let Some(p) = path {
-            assert!(p.to_string_lossy().contains("settings.toml"));
-        }
-    }
-
     #[test]
     fn test_newrelic_config_serialization() {
         let config = NewRelicConfig {
@@ -233,18 +208,17 @@ mod tests {
 api_key = "NRAK-fromfile"
 account_id = 777
 "#;
-        let settings: SettingsFile = toml::from_str(toml).unwrap();
-        assert!(settings.newrelic.is_some());
-        let nr = settings.newrelic.unwrap();
+        let nr: NewRelicConfig = settings_section_from_toml(toml, "newrelic")
+            .unwrap()
+            .unwrap();
         assert_eq!(nr.api_key, Some("NRAK-fromfile".to_string()));
         assert_eq!(nr.account_id, Some(777));
     }
 
     #[test]
     fn test_settings_file_deserialization_empty() {
-        let toml = "";
-        let settings: SettingsFile = toml::from_str(toml).unwrap();
-        assert!(settings.newrelic.is_none());
+        let nr: Option<NewRelicConfig> = settings_section_from_toml("", "newrelic").unwrap();
+        assert!(nr.is_none());
     }
 
     #[test]
@@ -253,31 +227,7 @@ account_id = 777
 [sentry]
 auth_token = "secret"
 "#;
-        let settings: SettingsFile = toml::from_str(toml).unwrap();
-        assert!(settings.newrelic.is_none());
-    }
-
-    #[test]
-    fn test_settings_file_debug() {
-        let settings = SettingsFile { newrelic: None };
-        let debug = format!("{:?}", settings);
-        assert!(debug.contains("SettingsFile"));
-    }
-
-    #[test]
-    fn test_settings_file_default() {
-        let settings = SettingsFile::default();
-        assert!(settings.newrelic.is_none());
-    }
-
-    #[test]
-    fn test_config_path_hu_directory() {
-        if let Some(path) = config_path() {
-            // Should be in ~/.config/hu/settings.toml
-            let path_str = path.to_string_lossy();
-            assert!(path_str.contains(".config"));
-            assert!(path_str.contains("hu"));
-            assert!(path_str.ends_with("settings.toml"));
-        }
+        let nr: Option<NewRelicConfig> = settings_section_from_toml(toml, "newrelic").unwrap();
+        assert!(nr.is_none());
     }
 }
@@ -21,6 +21,8 @@ pub mod types;
 use anyhow::Result;
 use clap::Subcommand;
 
+use crate::util::timings;
+
 use client::NewRelicClient;
 pub use config::NewRelicConfig;
 use types::OutputFormat;
@@ -163,6 +165,7 @@ async fn cmd_issues(limit: usize, json: bool) -> Result<()> {
         OutputFormat::Table
     };
 
+    let _span = timings::span("render");
     display::output_issues(&issues, format)?;
     Ok(())
 }
@@ -182,6 +185,7 @@ async fn cmd_incidents(limit: usize, json: bool) -> Result<()> {
         OutputFormat::Table
     };
 
+    let _span = timings::span("render");
     display::output_incidents(&incidents, format)?;
     Ok(())
 }
@@ -201,6 +205,7 @@ async fn cmd_query(nrql: &str, json: bool) -> Result<()> {
         OutputFormat::Table
     };
 
+    let _span = timings::span("render");
     display::output_nrql(&results, format)?;
     Ok(())
 }
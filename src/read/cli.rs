@@ -1,5 +1,7 @@
 use clap::Args;
 
+use super::encoding::Encoding;
+
 #[derive(Debug, Args)]
 pub struct ReadArgs {
     /// File path to read
@@ -9,6 +11,10 @@ pub struct ReadArgs {
     #[arg(long, short = 'o')]
     pub outline: bool,
 
+    /// Include per-item metrics in the outline (lines, complexity, TODOs)
+    #[arg(long, short = 'm', requires = "outline")]
+    pub metrics: bool,
+
     /// Show public interface only (pub items in Rust, exports in JS)
     #[arg(long, short = 'i')]
     pub interface: bool,
@@ -28,6 +34,40 @@ pub struct ReadArgs {
     /// Commit to diff against (default: HEAD)
     #[arg(long, default_value = "HEAD")]
     pub commit: String,
+
+    /// Append public interfaces of imported files, following imports DEPTH levels deep
+    #[arg(
+        long,
+        value_name = "DEPTH",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "1"
+    )]
+    pub follow_imports: Option<usize>,
+
+    /// Source encoding (default: detect from BOM and content)
+    #[arg(long, value_enum)]
+    pub encoding: Option<Encoding>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = ReadFormat::Text)]
+    pub format: ReadFormat,
+
+    /// Replace secrets (keys, tokens, passwords) with placeholders
+    #[arg(long)]
+    pub redact: bool,
+}
+
+/// Output format for `hu read`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadFormat {
+    /// Plain text layout
+    #[default]
+    Text,
+    /// Structured JSON for tooling
+    Json,
+    /// Markdown with heading hierarchy
+    Md,
 }
 
 impl ReadArgs {
@@ -71,6 +111,18 @@ mod tests {
         assert!(cli.read.outline);
     }
 
+    #[test]
+    fn parse_metrics_flag() {
+        let cli = TestCli::try_parse_from(["test", "-o", "--metrics", "file.rs"]).unwrap();
+        assert!(cli.read.outline);
+        assert!(cli.read.metrics);
+    }
+
+    #[test]
+    fn metrics_requires_outline() {
+        assert!(TestCli::try_parse_from(["test", "--metrics", "file.rs"]).is_err());
+    }
+
     #[test]
     fn parse_interface_long() {
         let cli = TestCli::try_parse_from(["test", "--interface", "file.rs"]).unwrap();
@@ -122,6 +174,30 @@ mod tests {
         assert_eq!(cli.read.commit, "HEAD~1");
     }
 
+    #[test]
+    fn parse_follow_imports() {
+        let cli = TestCli::try_parse_from(["test", "--follow-imports", "file.rs"]).unwrap();
+        assert_eq!(cli.read.follow_imports, Some(1));
+        assert_eq!(cli.read.path, "file.rs");
+
+        let cli = TestCli::try_parse_from(["test", "--follow-imports=3", "file.rs"]).unwrap();
+        assert_eq!(cli.read.follow_imports, Some(3));
+
+        let cli = TestCli::try_parse_from(["test", "file.rs"]).unwrap();
+        assert!(cli.read.follow_imports.is_none());
+    }
+
+    #[test]
+    fn parse_format() {
+        let cli = TestCli::try_parse_from(["test", "file.rs"]).unwrap();
+        assert_eq!(cli.read.format, ReadFormat::Text);
+
+        let cli = TestCli::try_parse_from(["test", "-o", "--format", "md", "file.rs"]).unwrap();
+        assert_eq!(cli.read.format, ReadFormat::Md);
+
+        assert!(TestCli::try_parse_from(["test", "--format", "xml", "file.rs"]).is_err());
+    }
+
     #[test]
     fn has_mode_none() {
         let cli = TestCli::try_parse_from(["test", "file.rs"]).unwrap();
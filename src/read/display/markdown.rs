@@ -0,0 +1,133 @@
+//! Markdown rendering of read output (`--format md`)
+
+use crate::read::around::format_lines_around;
+use crate::read::types::{ImportedInterface, ItemMetrics, OutlineItem, ReadOutput};
+
+/// Format ReadOutput as Markdown
+pub fn format_markdown(output: &ReadOutput) -> String {
+    match output {
+        ReadOutput::Full(content) => fence("", content),
+        ReadOutput::Outline(outline) => format_items(&outline.items, "No outline items found"),
+        ReadOutput::Interface(items) => format_items(items, "No public interface items found"),
+        ReadOutput::Around {
+            lines,
+            center,
+            total_lines,
+        } => fence("", &format_lines_around(lines, *center, *total_lines)),
+        ReadOutput::Diff(diff) => fence("diff", diff),
+        ReadOutput::WithImports { output, imports } => {
+            format!("{}\n{}", format_markdown(output), format_imports(imports))
+        }
+        ReadOutput::Decoded { encoding, output } => {
+            format!(
+                "> Decoded from {}\n\n{}",
+                encoding.label(),
+                format_markdown(output)
+            )
+        }
+    }
+}
+
+/// One heading per item, nested by outline level (`##` for top level)
+fn format_items(items: &[OutlineItem], empty: &str) -> String {
+    if items.is_empty() {
+        return format!("_{}_\n", empty);
+    }
+
+    items
+        .iter()
+        .map(|item| {
+            let hashes = "#".repeat((item.level + 2).min(6));
+            format!(
+                "{} {}\n\n{}\n",
+                hashes,
+                code(&item.text),
+                item_details(item)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `fn · line 12 · 8 lines · complexity 2`
+fn item_details(item: &OutlineItem) -> String {
+    let mut parts = Vec::new();
+    if !item.kind.icon().is_empty() {
+        parts.push(item.kind.icon().to_string());
+    }
+    parts.push(format!("line {}", item.line));
+    if let Some(metrics) = &item.metrics {
+        parts.extend(metric_parts(metrics));
+    }
+    parts.join(" · ")
+}
+
+fn metric_parts(metrics: &ItemMetrics) -> Vec<String> {
+    let mut parts = vec![
+        count(metrics.lines, "line", "lines"),
+        format!("complexity {}", metrics.complexity),
+    ];
+    if metrics.todos > 0 {
+        parts.push(count(metrics.todos, "todo", "todos"));
+    }
+    parts
+}
+
+/// `## Imports` section with a bullet list of public items per file
+fn format_imports(imports: &[ImportedInterface]) -> String {
+    let mut out = String::from("## Imports\n");
+    if imports.is_empty() {
+        out.push_str("\n_No imports resolved_\n");
+    }
+    for import in imports {
+        out.push_str(&format!(
+            "\n### {} (depth {})\n\n",
+            code(&import.path),
+            import.depth
+        ));
+        if import.items.is_empty() {
+            out.push_str("_No public interface items found_\n");
+        }
+        for item in &import.items {
+            out.push_str(&format!(
+                "- {} ({})\n",
+                code(&item.text),
+                item_details(item)
+            ));
+        }
+    }
+    out
+}
+
+/// `1 line`, `2 lines`
+fn count(n: usize, one: &str, many: &str) -> String {
+    format!("{} {}", n, if n == 1 { one } else { many })
+}
+
+/// Longest run of consecutive backticks in `text`
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+/// Inline code span, using a longer delimiter (and padding) when the text
+/// itself contains backticks
+fn code(text: &str) -> String {
+    let ticks = "`".repeat(longest_backtick_run(text) + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{} {} {}", ticks, text, ticks)
+    } else {
+        format!("{}{}{}", ticks, text, ticks)
+    }
+}
+
+/// Fenced code block, lengthening the fence past any backtick run in the body
+fn fence(lang: &str, body: &str) -> String {
+    let ticks = "`".repeat((longest_backtick_run(body) + 1).max(3));
+    format!(
+        "{}{}\n{}\n{}\n",
+        ticks,
+        lang,
+        body.trim_end_matches('\n'),
+        ticks
+    )
+}
@@ -0,0 +1,118 @@
+//! Display formatting for read output (CLI-only)
+
+use super::around::format_lines_around;
+use super::cli::ReadFormat;
+use super::diff::format_diff;
+use super::types::{FileOutline, ImportedInterface, ItemMetrics, OutlineItem, ReadOutput};
+
+mod markdown;
+
+#[cfg(test)]
+mod tests;
+
+/// Format ReadOutput in the requested output format
+pub fn render(output: &ReadOutput, format: ReadFormat) -> String {
+    match format {
+        ReadFormat::Text => self::format(output),
+        ReadFormat::Json => format_json(output) + "\n",
+        ReadFormat::Md => markdown::format_markdown(output),
+    }
+}
+
+/// Format ReadOutput as pretty-printed JSON (`{"mode": ..., "data": ...}`)
+pub fn format_json(output: &ReadOutput) -> String {
+    serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Format ReadOutput for CLI display
+pub fn format(output: &ReadOutput) -> String {
+    match output {
+        ReadOutput::Full(content) => content.clone(),
+        ReadOutput::Outline(outline) => format_outline(outline),
+        ReadOutput::Interface(items) => format_interface(items),
+        ReadOutput::Around {
+            lines,
+            center,
+            total_lines,
+        } => format_lines_around(lines, *center, *total_lines),
+        ReadOutput::Diff(diff) => format_diff(diff),
+        ReadOutput::WithImports { output, imports } => format_with_imports(output, imports),
+        ReadOutput::Decoded { encoding, output } => {
+            format!("[decoded from {}]\n{}", encoding.label(), format(output))
+        }
+    }
+}
+
+/// Format outline for display
+fn format_outline(outline: &FileOutline) -> String {
+    if outline.is_empty() {
+        return "No outline items found".to_string();
+    }
+
+    let mut output = Vec::new();
+
+    for item in &outline.items {
+        let indent = "  ".repeat(item.level);
+        let icon = item.kind.icon();
+        let line_info = format!(":{}", item.line);
+        let metrics = item
+            .metrics
+            .as_ref()
+            .map(format_metrics)
+            .unwrap_or_default();
+        output.push(format!(
+            "{}{} {}{}{}",
+            indent, icon, item.text, line_info, metrics
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// Format the metrics column appended to an outline line
+fn format_metrics(metrics: &ItemMetrics) -> String {
+    let mut parts = vec![
+        format!("{}L", metrics.lines),
+        format!("cc {}", metrics.complexity),
+    ];
+    if metrics.todos > 0 {
+        parts.push(format!("{} todo", metrics.todos));
+    }
+    format!("  [{}]", parts.join(", "))
+}
+
+/// Format output followed by one interface section per imported file
+fn format_with_imports(output: &ReadOutput, imports: &[ImportedInterface]) -> String {
+    let mut sections = vec![format(output).trim_end().to_string()];
+
+    if imports.is_empty() {
+        sections.push("--- no imports resolved ---".to_string());
+    }
+    for import in imports {
+        sections.push(format!(
+            "--- {} (depth {}) ---\n{}",
+            import.path,
+            import.depth,
+            format_interface(&import.items)
+        ));
+    }
+
+    sections.join("\n\n") + "\n"
+}
+
+/// Format interface for display
+fn format_interface(items: &[OutlineItem]) -> String {
+    if items.is_empty() {
+        return "No public interface items found".to_string();
+    }
+
+    let mut output = Vec::new();
+
+    for item in items {
+        let indent = "  ".repeat(item.level);
+        let icon = item.kind.icon();
+        output.push(format!("{}{} {} :L{}", indent, icon, item.text, item.line));
+    }
+
+    output.join("\n")
+}
@@ -0,0 +1,323 @@
+use super::*;
+use crate::read::encoding::Encoding;
+use crate::read::types::ItemKind;
+
+#[test]
+fn format_full_content() {
+    let output = ReadOutput::Full("hello\nworld".to_string());
+    let formatted = format(&output);
+    assert_eq!(formatted, "hello\nworld");
+}
+
+#[test]
+fn format_empty_outline() {
+    let output = ReadOutput::Outline(FileOutline::new());
+    let formatted = format(&output);
+    assert_eq!(formatted, "No outline items found");
+}
+
+#[test]
+fn format_outline_with_items() {
+    let mut outline = FileOutline::new();
+    outline.push(OutlineItem::new(
+        10,
+        "pub fn test()".to_string(),
+        0,
+        ItemKind::Function,
+    ));
+    let output = ReadOutput::Outline(outline);
+    let formatted = format(&output);
+    assert!(formatted.contains("fn pub fn test()"));
+    assert!(formatted.contains(":10"));
+}
+
+#[test]
+fn format_nested_outline() {
+    let mut outline = FileOutline::new();
+    outline.push(OutlineItem::new(
+        1,
+        "impl Config".to_string(),
+        0,
+        ItemKind::Impl,
+    ));
+    outline.push(OutlineItem::new(
+        2,
+        "pub fn new()".to_string(),
+        1,
+        ItemKind::Function,
+    ));
+    let output = ReadOutput::Outline(outline);
+    let formatted = format(&output);
+    let lines: Vec<&str> = formatted.lines().collect();
+    assert!(lines[0].starts_with("impl"));
+    assert!(lines[1].starts_with("  fn")); // Indented
+}
+
+#[test]
+fn format_outline_with_metrics() {
+    let mut item = OutlineItem::new(10, "fn run()".to_string(), 0, ItemKind::Function);
+    item.metrics = Some(ItemMetrics {
+        lines: 42,
+        complexity: 7,
+        todos: 2,
+    });
+    let mut outline = FileOutline::new();
+    outline.push(item);
+    let formatted = format(&ReadOutput::Outline(outline));
+    assert!(formatted.ends_with(":10  [42L, cc 7, 2 todo]"));
+}
+
+#[test]
+fn format_metrics_omits_zero_todos() {
+    let metrics = ItemMetrics {
+        lines: 3,
+        complexity: 1,
+        todos: 0,
+    };
+    assert_eq!(format_metrics(&metrics), "  [3L, cc 1]");
+}
+
+#[test]
+fn format_empty_interface() {
+    let output = ReadOutput::Interface(vec![]);
+    let formatted = format(&output);
+    assert_eq!(formatted, "No public interface items found");
+}
+
+#[test]
+fn format_interface_with_items() {
+    let items = vec![OutlineItem::new(
+        10,
+        "pub fn test()".to_string(),
+        0,
+        ItemKind::Function,
+    )];
+    let output = ReadOutput::Interface(items);
+    let formatted = format(&output);
+    assert!(formatted.contains("fn pub fn test()"));
+    assert!(formatted.contains(":L10"));
+}
+
+#[test]
+fn format_around_lines() {
+    let output = ReadOutput::Around {
+        lines: vec![
+            (9, "line9".to_string()),
+            (10, "line10".to_string()),
+            (11, "line11".to_string()),
+        ],
+        center: 10,
+        total_lines: 11, // width is 2, so format is ">10: line10"
+    };
+    let formatted = format(&output);
+    assert!(formatted.contains(">10: line10"));
+    assert!(formatted.contains(" 9: line9"));
+}
+
+#[test]
+fn format_diff_content() {
+    let output = ReadOutput::Diff("+added line".to_string());
+    let formatted = format(&output);
+    assert!(formatted.contains("+added line"));
+    // Should have green color for additions
+    assert!(formatted.contains("\x1b[32m"));
+}
+
+#[test]
+fn format_diff_no_changes() {
+    let output = ReadOutput::Diff("No changes".to_string());
+    let formatted = format(&output);
+    assert_eq!(formatted, "No changes");
+}
+
+#[test]
+fn format_with_imports_appends_sections() {
+    let output = ReadOutput::WithImports {
+        output: Box::new(ReadOutput::Full("use super::types;\n".to_string())),
+        imports: vec![ImportedInterface {
+            path: "src/types.rs".to_string(),
+            depth: 1,
+            items: vec![OutlineItem::new(
+                3,
+                "pub struct Item".to_string(),
+                0,
+                ItemKind::Struct,
+            )],
+        }],
+    };
+    assert_eq!(
+        format(&output),
+        "use super::types;\n\n--- src/types.rs (depth 1) ---\nstruct pub struct Item :L3\n"
+    );
+}
+
+#[test]
+fn format_with_no_imports() {
+    let output = ReadOutput::WithImports {
+        output: Box::new(ReadOutput::Full("x".to_string())),
+        imports: vec![],
+    };
+    assert_eq!(format(&output), "x\n\n--- no imports resolved ---\n");
+}
+
+#[test]
+fn format_decoded_adds_note() {
+    let output = ReadOutput::Decoded {
+        encoding: crate::read::encoding::Encoding::Utf16Le,
+        output: Box::new(ReadOutput::Full("hello".to_string())),
+    };
+    assert_eq!(format(&output), "[decoded from UTF-16LE]\nhello");
+}
+
+fn metrics_outline() -> ReadOutput {
+    let mut outline = FileOutline::new();
+    let mut item = OutlineItem::new(3, "impl Foo".to_string(), 0, ItemKind::Impl);
+    item.metrics = Some(ItemMetrics {
+        lines: 20,
+        complexity: 4,
+        todos: 1,
+    });
+    outline.push(item);
+    outline.push(OutlineItem::new(
+        5,
+        "pub fn new()".to_string(),
+        1,
+        ItemKind::Function,
+    ));
+    ReadOutput::Outline(outline)
+}
+
+#[test]
+fn render_text_matches_format() {
+    let output = ReadOutput::Full("x".to_string());
+    assert_eq!(render(&output, ReadFormat::Text), format(&output));
+}
+
+#[test]
+fn json_outline_includes_metrics_only_when_present() {
+    let json: serde_json::Value = serde_json::from_str(&format_json(&metrics_outline())).unwrap();
+    assert_eq!(json["mode"], "outline");
+    let items = &json["data"]["items"];
+    assert_eq!(items[0]["kind"], "impl");
+    assert_eq!(items[0]["metrics"]["complexity"], 4);
+    assert_eq!(items[1]["level"], 1);
+    assert!(items[1].get("metrics").is_none());
+}
+
+#[test]
+fn json_decoded_and_imports() {
+    let output = ReadOutput::Decoded {
+        encoding: Encoding::Latin1,
+        output: Box::new(ReadOutput::WithImports {
+            output: Box::new(ReadOutput::Full("café".to_string())),
+            imports: vec![ImportedInterface {
+                path: "a.py".to_string(),
+                depth: 1,
+                items: vec![],
+            }],
+        }),
+    };
+    let json: serde_json::Value = serde_json::from_str(&render(&output, ReadFormat::Json)).unwrap();
+    assert_eq!(json["mode"], "decoded");
+    assert_eq!(json["data"]["encoding"], "latin-1");
+    let inner = &json["data"]["output"];
+    assert_eq!(inner["mode"], "with_imports");
+    assert_eq!(inner["data"]["output"]["data"], "café");
+    assert_eq!(inner["data"]["imports"][0]["path"], "a.py");
+}
+
+#[test]
+fn json_around_and_heading_kind() {
+    let around = ReadOutput::Around {
+        lines: vec![(2, "b".to_string())],
+        center: 2,
+        total_lines: 3,
+    };
+    let json: serde_json::Value = serde_json::from_str(&format_json(&around)).unwrap();
+    assert_eq!(json["data"]["lines"][0][1], "b");
+
+    let heading = ReadOutput::Interface(vec![OutlineItem::new(
+        1,
+        "Intro".to_string(),
+        0,
+        ItemKind::Heading(2),
+    )]);
+    let json: serde_json::Value = serde_json::from_str(&format_json(&heading)).unwrap();
+    assert_eq!(json["data"][0]["kind"]["heading"], 2);
+}
+
+#[test]
+fn markdown_outline_heading_hierarchy() {
+    let md = render(&metrics_outline(), ReadFormat::Md);
+    assert_eq!(
+        md,
+        "## `impl Foo`\n\nimpl · line 3 · 20 lines · complexity 4 · 1 todo\n\n\
+         ### `pub fn new()`\n\nfn · line 5\n"
+    );
+}
+
+#[test]
+fn markdown_pluralizes_metrics() {
+    let mut item = OutlineItem::new(1, "fn one()".to_string(), 0, ItemKind::Function);
+    item.metrics = Some(ItemMetrics {
+        lines: 1,
+        complexity: 1,
+        todos: 2,
+    });
+    let mut outline = FileOutline::new();
+    outline.push(item);
+    let md = render(&ReadOutput::Outline(outline), ReadFormat::Md);
+    assert!(md.contains("· 1 line · complexity 1 · 2 todos"));
+}
+
+#[test]
+fn markdown_code_spans_survive_backticks() {
+    let items = vec![
+        OutlineItem::new(1, "Use `hu` here".to_string(), 0, ItemKind::Heading(1)),
+        OutlineItem::new(2, "`x`".to_string(), 0, ItemKind::Heading(1)),
+    ];
+    let md = render(&ReadOutput::Interface(items), ReadFormat::Md);
+    assert!(md.contains("## ``Use `hu` here``\n"));
+    assert!(md.contains("## `` `x` ``\n"));
+}
+
+#[test]
+fn markdown_empty_interface() {
+    let md = render(&ReadOutput::Interface(vec![]), ReadFormat::Md);
+    assert_eq!(md, "_No public interface items found_\n");
+}
+
+#[test]
+fn markdown_fences_content_and_diff() {
+    let md = render(&ReadOutput::Full("a\n```\nb\n".to_string()), ReadFormat::Md);
+    assert_eq!(md, "````\na\n```\nb\n````\n");
+    let md = render(&ReadOutput::Full("````\n".to_string()), ReadFormat::Md);
+    assert_eq!(md, "`````\n````\n`````\n");
+    let md = render(&ReadOutput::Diff("+x".to_string()), ReadFormat::Md);
+    assert_eq!(md, "```diff\n+x\n```\n");
+}
+
+#[test]
+fn markdown_decoded_with_imports() {
+    let output = ReadOutput::Decoded {
+        encoding: Encoding::Utf16Le,
+        output: Box::new(ReadOutput::WithImports {
+            output: Box::new(ReadOutput::Full("x".to_string())),
+            imports: vec![ImportedInterface {
+                path: "src/a.rs".to_string(),
+                depth: 2,
+                items: vec![OutlineItem::new(
+                    4,
+                    "pub fn a()".to_string(),
+                    0,
+                    ItemKind::Function,
+                )],
+            }],
+        }),
+    };
+    assert_eq!(
+        render(&output, ReadFormat::Md),
+        "> Decoded from UTF-16LE\n\n```\nx\n```\n\n## Imports\n\n\
+         ### `src/a.rs` (depth 2)\n\n- `pub fn a()` (fn · line 4)\n"
+    );
+}
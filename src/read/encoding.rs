@@ -0,0 +1,204 @@
+//! Text encoding detection and conversion to UTF-8
+
+/// Bytes sampled when guessing UTF-16 without a BOM
+const SNIFF_LEN: usize = 4096;
+
+/// Share of C0 control bytes (per mille) above which content is treated as binary
+const MAX_CONTROL_PER_MILLE: usize = 100;
+
+/// Text encoding of a file on disk
+#[derive(clap::ValueEnum, serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    #[value(name = "utf-8", alias = "utf8")]
+    #[serde(rename = "utf-8")]
+    Utf8,
+    /// UTF-8 with a byte order mark (detected only)
+    #[value(skip)]
+    #[serde(rename = "utf-8-bom")]
+    Utf8Bom,
+    #[value(name = "utf-16le", alias = "utf16le")]
+    #[serde(rename = "utf-16le")]
+    Utf16Le,
+    #[value(name = "utf-16be", alias = "utf16be")]
+    #[serde(rename = "utf-16be")]
+    Utf16Be,
+    #[value(name = "latin-1", alias = "latin1", alias = "iso-8859-1")]
+    #[serde(rename = "latin-1")]
+    Latin1,
+}
+
+impl Encoding {
+    /// Human-readable name
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Utf8 => "UTF-8",
+            Self::Utf8Bom => "UTF-8 (BOM)",
+            Self::Utf16Le => "UTF-16LE",
+            Self::Utf16Be => "UTF-16BE",
+            Self::Latin1 => "Latin-1",
+        }
+    }
+}
+
+/// Detect encoding from a BOM, falling back to heuristics.
+///
+/// NUL bytes concentrated on odd (even) positions suggest UTF-16LE (BE).
+/// Other content containing NULs or many control bytes is likely binary
+/// and yields `None`; otherwise valid UTF-8 wins, then Latin-1.
+pub fn detect(bytes: &[u8]) -> Option<Encoding> {
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        return Some(Encoding::Utf8Bom);
+    }
+    if bytes.starts_with(&[0xFF, 0xFE]) {
+        return Some(Encoding::Utf16Le);
+    }
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        return Some(Encoding::Utf16Be);
+    }
+
+    let sample = &bytes[..bytes.len().min(SNIFF_LEN)];
+    if let Some(utf16) = sniff_utf16(sample) {
+        return Some(utf16);
+    }
+    if looks_binary(sample) {
+        return None;
+    }
+    if std::str::from_utf8(bytes).is_ok() {
+        Some(Encoding::Utf8)
+    } else {
+        Some(Encoding::Latin1)
+    }
+}
+
+/// UTF-16 without a BOM: NULs on one parity only (ASCII-heavy text)
+fn sniff_utf16(sample: &[u8]) -> Option<Encoding> {
+    let pairs = (sample.len() / 2).max(1);
+    let even_nuls = sample.iter().step_by(2).filter(|&&b| b == 0).count();
+    let odd_nuls = sample
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .filter(|&&b| b == 0)
+        .count();
+
+    if odd_nuls * 10 > pairs * 3 && even_nuls * 20 < pairs {
+        Some(Encoding::Utf16Le)
+    } else if even_nuls * 10 > pairs * 3 && odd_nuls * 20 < pairs {
+        Some(Encoding::Utf16Be)
+    } else {
+        None
+    }
+}
+
+/// Any NUL, or too many C0 controls other than whitespace and ESC
+fn looks_binary(sample: &[u8]) -> bool {
+    if sample.contains(&0) {
+        return true;
+    }
+    let controls = sample
+        .iter()
+        .filter(|&&b| b < 0x20 && !matches!(b, b'\t' | b'\n' | b'\r' | 0x0C | 0x1B))
+        .count();
+    controls * 1000 > sample.len() * MAX_CONTROL_PER_MILLE
+}
+
+/// Decode bytes as `encoding`, dropping any BOM. Invalid sequences become U+FFFD.
+pub fn decode(bytes: &[u8], encoding: Encoding) -> String {
+    match encoding {
+        Encoding::Utf8 | Encoding::Utf8Bom => {
+            let body = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+            String::from_utf8_lossy(body).into_owned()
+        }
+        Encoding::Utf16Le => decode_utf16(bytes, [0xFF, 0xFE], u16::from_le_bytes),
+        Encoding::Utf16Be => decode_utf16(bytes, [0xFE, 0xFF], u16::from_be_bytes),
+        Encoding::Latin1 => bytes.iter().map(|&b| char::from(b)).collect(),
+    }
+}
+
+fn decode_utf16(bytes: &[u8], bom: [u8; 2], to_unit: fn([u8; 2]) -> u16) -> String {
+    let body = bytes.strip_prefix(&bom).unwrap_or(bytes);
+    let units: Vec<u16> = body
+        .chunks_exact(2)
+        .map(|pair| to_unit([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16le(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+    }
+
+    fn utf16be(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(|u| u.to_be_bytes()).collect()
+    }
+
+    #[test]
+    fn detect_plain_utf8() {
+        assert_eq!(detect("héllo\n".as_bytes()), Some(Encoding::Utf8));
+        assert_eq!(detect(b""), Some(Encoding::Utf8));
+    }
+
+    #[test]
+    fn detect_boms() {
+        assert_eq!(detect(b"\xEF\xBB\xBFhi"), Some(Encoding::Utf8Bom));
+        assert_eq!(detect(b"\xFF\xFEh\0"), Some(Encoding::Utf16Le));
+        assert_eq!(detect(b"\xFE\xFF\0h"), Some(Encoding::Utf16Be));
+    }
+
+    #[test]
+    fn detect_utf16_without_bom() {
+        assert_eq!(detect(&utf16le("fn main() {}\n")), Some(Encoding::Utf16Le));
+        assert_eq!(detect(&utf16be("fn main() {}\n")), Some(Encoding::Utf16Be));
+    }
+
+    #[test]
+    fn detect_latin1() {
+        // "café" with é as 0xE9 is invalid UTF-8
+        assert_eq!(detect(b"caf\xE9\n"), Some(Encoding::Latin1));
+    }
+
+    #[test]
+    fn detect_rejects_binary() {
+        // PNG signature + IHDR chunk header
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\x01\0\0\0\x01\0\x08\x06\0\0\0";
+        assert_eq!(detect(png), None);
+        // No NULs, but mostly control bytes
+        assert_eq!(detect(b"\x01\x02\x03\x04ab\x05\x06"), None);
+        // Escape sequences and form feeds are still text
+        assert_eq!(detect(b"\x1b[1mbold\x1b[0m\x0c\n"), Some(Encoding::Utf8));
+    }
+
+    #[test]
+    fn decode_strips_utf8_bom() {
+        assert_eq!(decode(b"\xEF\xBB\xBFhi", Encoding::Utf8Bom), "hi");
+        assert_eq!(decode(b"\xEF\xBB\xBFhi", Encoding::Utf8), "hi");
+    }
+
+    #[test]
+    fn decode_utf16_both_orders() {
+        let mut le = vec![0xFF, 0xFE];
+        le.extend(utf16le("héllo"));
+        assert_eq!(decode(&le, Encoding::Utf16Le), "héllo");
+        assert_eq!(decode(&utf16be("héllo"), Encoding::Utf16Be), "héllo");
+    }
+
+    #[test]
+    fn decode_latin1() {
+        assert_eq!(decode(b"caf\xE9", Encoding::Latin1), "café");
+    }
+
+    #[test]
+    fn decode_invalid_utf8_is_lossy() {
+        assert_eq!(decode(b"a\xFFb", Encoding::Utf8), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(Encoding::Utf8Bom.label(), "UTF-8 (BOM)");
+        assert_eq!(Encoding::Latin1.label(), "Latin-1");
+    }
+}
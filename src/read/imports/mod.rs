@@ -0,0 +1,348 @@
+use regex::Regex;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use super::encoding::{decode, detect};
+use super::interface::extract_interface;
+use super::types::ImportedInterface;
+
+#[cfg(test)]
+mod tests;
+
+/// JS/TS extensions tried when resolving extensionless relative imports
+const JS_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mjs"];
+
+/// Public interfaces of files imported by `path`, breadth-first up to `depth` hops
+pub fn follow_imports(path: &Path, content: &str, depth: usize) -> Vec<ImportedInterface> {
+    let root = project_root(path);
+    let mut seen: HashSet<PathBuf> = HashSet::from([canonical(path)]);
+    let mut queue = VecDeque::from([(path.to_path_buf(), content.to_string(), 0)]);
+    let mut imported = Vec::new();
+
+    while let Some((file, source, level)) = queue.pop_front() {
+        if level >= depth {
+            continue;
+        }
+        for dep in resolve_imports(&file, &source, &root) {
+            if !seen.insert(canonical(&dep)) {
+                continue;
+            }
+            let Ok(bytes) = fs::read(&dep) else {
+                continue;
+            };
+            let Some(encoding) = detect(&bytes) else {
+                continue;
+            };
+            let dep_source = decode(&bytes, encoding);
+            imported.push(ImportedInterface {
+                path: dep
+                    .strip_prefix(&root)
+                    .unwrap_or(&dep)
+                    .display()
+                    .to_string(),
+                depth: level + 1,
+                items: extract_interface(&dep_source, dep.to_str().unwrap_or("")),
+            });
+            queue.push_back((dep, dep_source, level + 1));
+        }
+    }
+
+    imported
+}
+
+/// Files in the project referenced by the import statements of `file`
+fn resolve_imports(file: &Path, content: &str, root: &Path) -> Vec<PathBuf> {
+    let ext = file.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let dir = file.parent().unwrap_or(Path::new("."));
+
+    let resolved: Vec<PathBuf> = match ext {
+        "rs" => resolve_rust(file, content),
+        "py" => resolve_python(dir, content, root),
+        "js" | "ts" | "jsx" | "tsx" | "mjs" => resolve_js(dir, content),
+        "rb" => resolve_ruby(dir, content),
+        _ => vec![],
+    };
+
+    let mut unique = Vec::new();
+    for path in resolved.iter().map(|p| normalize(p)) {
+        if !unique.contains(&path) {
+            unique.push(path);
+        }
+    }
+    unique
+}
+
+/// Resolve `use crate::/self::/super::` paths and `mod x;` declarations
+fn resolve_rust(file: &Path, content: &str) -> Vec<PathBuf> {
+    let mod_dir = rust_module_dir(file);
+    let crate_src = crate_src_dir(file);
+    let mut found = Vec::new();
+
+    for stmt in rust_statements(content) {
+        if let Some(name) = stmt.strip_prefix("mod ") {
+            found.extend(module_file(&mod_dir.join(name.trim())));
+            continue;
+        }
+        let Some(tree) = stmt.strip_prefix("use ") else {
+            continue;
+        };
+        for path in expand_use_tree(tree) {
+            let segments: Vec<&str> = path.split("::").map(str::trim).collect();
+            let (base, rest) = match segments.split_first() {
+                Some((&"crate", rest)) => match &crate_src {
+                    Some(src) => (src.clone(), rest),
+                    None => continue,
+                },
+                Some((&"self", rest)) => (mod_dir.clone(), rest),
+                Some((&"super", _)) => {
+                    let supers = segments.iter().take_while(|s| **s == "super").count();
+                    let mut base = mod_dir.clone();
+                    for _ in 0..supers {
+                        base.pop();
+                    }
+                    (base, &segments[supers..])
+                }
+                _ => continue,
+            };
+            found.extend(longest_module_prefix(&base, rest));
+        }
+    }
+
+    found
+}
+
+/// Top-level `use`/`mod` statements (visibility stripped, joined across lines)
+fn rust_statements(content: &str) -> Vec<String> {
+    let vis_re = Regex::new(r"^pub(\([^)]*\))?\s+").expect("invariant: visibility regex is valid");
+    let mut statements = Vec::new();
+    let mut current: Option<String> = None;
+    let mut test_only = false;
+
+    for line in content.lines() {
+        if let Some(stmt) = current.as_mut() {
+            stmt.push(' ');
+            stmt.push_str(line.trim());
+        } else if !line.starts_with(char::is_whitespace) {
+            let line = vis_re.replace(line, "");
+            let is_mod = line.starts_with("mod ") && line.ends_with(';');
+            if line.starts_with("use ") || (is_mod && !test_only) {
+                current = Some(line.trim().to_string());
+            }
+            test_only = line.starts_with("#[cfg(test)]");
+        }
+        if current.as_ref().is_some_and(|s| s.ends_with(';')) {
+            let stmt = current.take().expect("invariant: statement is in progress");
+            statements.push(stmt.trim_end_matches(';').to_string());
+        }
+    }
+
+    statements
+}
+
+/// Flatten a use tree like `super::{a::X, b::{self, Y}}` into plain paths
+fn expand_use_tree(tree: &str) -> Vec<String> {
+    let tree = tree.trim();
+    let Some(open) = tree.find('{') else {
+        let path = tree.split(" as ").next().unwrap_or(tree).trim();
+        return vec![path.trim_end_matches("::self").to_string()];
+    };
+
+    let prefix = tree[..open].trim_end_matches("::");
+    let inner = tree[open + 1..].trim_end().trim_end_matches('}');
+    split_top_level(inner)
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| {
+            if part == "self" {
+                vec![prefix.to_string()]
+            } else {
+                expand_use_tree(&format!("{}::{}", prefix, part))
+            }
+        })
+        .collect()
+}
+
+/// Split on commas that are not nested inside braces
+fn split_top_level(s: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+
+    for c in s.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts
+}
+
+/// Deepest module file named by a prefix of `segments` under `base`
+fn longest_module_prefix(base: &Path, segments: &[&str]) -> Option<PathBuf> {
+    (0..=segments.len()).rev().find_map(|n| {
+        let dir = segments[..n]
+            .iter()
+            .fold(base.to_path_buf(), |p, s| p.join(s));
+        module_file(&dir)
+    })
+}
+
+/// Source file for a Rust module whose directory would be `dir`
+fn module_file(dir: &Path) -> Option<PathBuf> {
+    [
+        dir.with_extension("rs"),
+        dir.join("mod.rs"),
+        dir.join("lib.rs"),
+        dir.join("main.rs"),
+    ]
+    .into_iter()
+    .find(|p| p.is_file())
+}
+
+/// Directory holding the child modules of the module defined in `file`
+fn rust_module_dir(file: &Path) -> PathBuf {
+    let parent = file.parent().unwrap_or(Path::new("."));
+    match file.file_stem().and_then(|s| s.to_str()) {
+        Some("mod" | "lib" | "main") | None => parent.to_path_buf(),
+        Some(stem) => parent.join(stem),
+    }
+}
+
+/// `src/` of the nearest enclosing Cargo crate
+fn crate_src_dir(file: &Path) -> Option<PathBuf> {
+    file.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map(|dir| dir.join("src"))
+}
+
+/// Resolve `import a.b` and `from .a import b` against the file's dir and the project root
+fn resolve_python(dir: &Path, content: &str, root: &Path) -> Vec<PathBuf> {
+    let import_re = Regex::new(r"^import\s+(.+)$").expect("invariant: import regex is valid");
+    let from_re = Regex::new(r"^from\s+(\.*)([\w.]*)\s+import\s+\(?([\w\s,*]*)")
+        .expect("invariant: from regex is valid");
+    let mut found = Vec::new();
+
+    for line in content.lines() {
+        if let Some(caps) = from_re.captures(line) {
+            let dots = caps[1].len();
+            let module = &caps[2];
+            let bases: Vec<PathBuf> = if dots > 0 {
+                let mut base = dir.to_path_buf();
+                for _ in 1..dots {
+                    base.pop();
+                }
+                vec![base]
+            } else {
+                vec![dir.to_path_buf(), root.to_path_buf()]
+            };
+            let names: Vec<String> = if module.is_empty() {
+                caps[3].split(',').map(|n| n.trim().to_string()).collect()
+            } else {
+                vec![module.to_string()]
+            };
+            for name in names {
+                found.extend(bases.iter().find_map(|b| python_module(b, &name)));
+            }
+        } else if let Some(caps) = import_re.captures(line) {
+            for name in caps[1].split(',') {
+                let name = name.split(" as ").next().unwrap_or("").trim();
+                found.extend([dir, root].iter().find_map(|b| python_module(b, name)));
+            }
+        }
+    }
+
+    found
+}
+
+fn python_module(base: &Path, dotted: &str) -> Option<PathBuf> {
+    if dotted.is_empty() || dotted == "*" {
+        return None;
+    }
+    let path = dotted.split('.').fold(base.to_path_buf(), |p, s| p.join(s));
+    [path.with_extension("py"), path.join("__init__.py")]
+        .into_iter()
+        .find(|p| p.is_file())
+}
+
+/// Resolve relative `import ... from './x'`, `import './x'` and `require('./x')`
+fn resolve_js(dir: &Path, content: &str) -> Vec<PathBuf> {
+    let spec_re = Regex::new(
+        r#"^(?:(?:import|export)\s[^'"]*?from\s*|\}\s*from\s*|import\s*|.*require\(\s*)['"](\.{1,2}/[^'"]+)['"]"#,
+    )
+    .expect("invariant: js import regex is valid");
+
+    content
+        .lines()
+        .filter_map(|line| spec_re.captures(line))
+        .filter_map(|caps| {
+            let base = dir.join(&caps[1]);
+            let with_ext = JS_EXTENSIONS
+                .iter()
+                .map(|ext| PathBuf::from(format!("{}.{}", base.display(), ext)));
+            let index = JS_EXTENSIONS
+                .iter()
+                .map(|ext| base.join(format!("index.{}", ext)));
+            std::iter::once(base.clone())
+                .chain(with_ext)
+                .chain(index)
+                .find(|p| p.is_file())
+        })
+        .collect()
+}
+
+/// Resolve `require_relative 'x'`
+fn resolve_ruby(dir: &Path, content: &str) -> Vec<PathBuf> {
+    let require_re = Regex::new(r#"^require_relative\s*\(?\s*['"]([^'"]+)['"]"#)
+        .expect("invariant: require_relative regex is valid");
+
+    content
+        .lines()
+        .filter_map(|line| require_re.captures(line))
+        .map(|caps| {
+            let path = dir.join(&caps[1]);
+            if path.extension().is_some() {
+                path
+            } else {
+                path.with_extension("rb")
+            }
+        })
+        .filter(|p| p.is_file())
+        .collect()
+}
+
+/// Nearest ancestor containing `.git`, or the file's own directory
+fn project_root(file: &Path) -> PathBuf {
+    let dir = file.parent().unwrap_or(Path::new("."));
+    dir.ancestors()
+        .find(|d| d.join(".git").exists())
+        .unwrap_or(dir)
+        .to_path_buf()
+}
+
+/// Lexically drop `.` and resolve `..` components (`src/./a/../b` -> `src/b`)
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if out.file_name().is_some() => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
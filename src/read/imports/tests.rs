@@ -0,0 +1,220 @@
+use super::*;
+
+/// Create files (relative path, content) under a fresh temp dir
+fn tree(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for (rel, content) in files {
+        let path = dir.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+fn names(paths: &[PathBuf], root: &Path) -> Vec<String> {
+    paths
+        .iter()
+        .map(|p| p.strip_prefix(root).unwrap().display().to_string())
+        .collect()
+}
+
+#[test]
+fn expand_simple_path() {
+    assert_eq!(
+        expand_use_tree("super::types::Item"),
+        vec!["super::types::Item"]
+    );
+    assert_eq!(expand_use_tree("crate::a as b"), vec!["crate::a"]);
+}
+
+#[test]
+fn expand_nested_groups() {
+    assert_eq!(
+        expand_use_tree("super::{a::X, b::{self, Y}, c}"),
+        vec!["super::a::X", "super::b", "super::b::Y", "super::c"]
+    );
+}
+
+#[test]
+fn rust_statements_join_lines_and_skip_test_mods() {
+    let content = "use std::fs;\npub(crate) use super::{\n    a,\n    b,\n};\npub mod cli;\n\
+                   #[cfg(test)]\nmod tests;\nfn f() {\n    use crate::x;\n}\n";
+    assert_eq!(
+        rust_statements(content),
+        vec!["use std::fs", "use super::{ a, b, }", "mod cli"]
+    );
+}
+
+#[test]
+fn rust_module_dir_for_mod_and_leaf_files() {
+    assert_eq!(
+        rust_module_dir(Path::new("src/read/mod.rs")),
+        Path::new("src/read")
+    );
+    assert_eq!(
+        rust_module_dir(Path::new("src/read/service.rs")),
+        Path::new("src/read/service")
+    );
+}
+
+#[test]
+fn resolve_rust_paths() {
+    let dir = tree(&[
+        ("Cargo.toml", "[package]"),
+        ("src/main.rs", "mod read;\n"),
+        ("src/util.rs", "pub fn helper() {}\n"),
+        ("src/read/mod.rs", "mod cli;\nmod service;\n"),
+        ("src/read/cli.rs", "pub struct Args;\n"),
+        ("src/read/types/mod.rs", "pub struct Item;\n"),
+        (
+            "src/read/service.rs",
+            "use std::fs;\nuse super::cli::Args;\nuse super::types::Item;\nuse crate::util::helper;\n",
+        ),
+    ]);
+    let root = dir.path();
+
+    let service = root.join("src/read/service.rs");
+    let content = fs::read_to_string(&service).unwrap();
+    assert_eq!(
+        names(&resolve_imports(&service, &content, root), root),
+        vec!["src/read/cli.rs", "src/read/types/mod.rs", "src/util.rs"]
+    );
+
+    let read_mod = root.join("src/read/mod.rs");
+    let content = fs::read_to_string(&read_mod).unwrap();
+    assert_eq!(
+        names(&resolve_imports(&read_mod, &content, root), root),
+        vec!["src/read/cli.rs", "src/read/service.rs"]
+    );
+}
+
+#[test]
+fn resolve_rust_glob_falls_back_to_parent_module() {
+    let dir = tree(&[
+        ("Cargo.toml", "[package]"),
+        ("src/lib.rs", "pub fn api() {}\n"),
+        ("src/a.rs", "use super::*;\nuse crate::Missing;\n"),
+    ]);
+    let root = dir.path();
+    let file = root.join("src/a.rs");
+    let content = fs::read_to_string(&file).unwrap();
+    assert_eq!(
+        names(&resolve_imports(&file, &content, root), root),
+        vec!["src/lib.rs"]
+    );
+}
+
+#[test]
+fn resolve_python_imports() {
+    let dir = tree(&[
+        (
+            "app/main.py",
+            "import os\nimport util\nfrom .models import User\nfrom pkg import (\n",
+        ),
+        ("app/util.py", ""),
+        ("app/models.py", ""),
+        ("pkg/__init__.py", ""),
+    ]);
+    let root = dir.path();
+    let file = root.join("app/main.py");
+    let content = fs::read_to_string(&file).unwrap();
+    assert_eq!(
+        names(&resolve_imports(&file, &content, root), root),
+        vec!["app/util.py", "app/models.py", "pkg/__init__.py"]
+    );
+}
+
+#[test]
+fn resolve_python_from_dot_import_names() {
+    let dir = tree(&[
+        ("pkg/sub/mod.py", "from .. import helpers, missing\n"),
+        ("pkg/helpers.py", ""),
+    ]);
+    let root = dir.path();
+    let file = root.join("pkg/sub/mod.py");
+    let content = fs::read_to_string(&file).unwrap();
+    assert_eq!(
+        names(&resolve_imports(&file, &content, root), root),
+        vec!["pkg/helpers.py"]
+    );
+}
+
+#[test]
+fn resolve_js_relative_specifiers() {
+    let dir = tree(&[
+        (
+            "src/app.ts",
+            "import React from 'react';\nimport { a } from './a';\nimport './style.css';\n\
+             export * from \"./lib\";\n} from '../shared';\nconst c = require('./c.js');\n",
+        ),
+        ("src/a.ts", ""),
+        ("src/style.css", ""),
+        ("src/lib/index.ts", ""),
+        ("shared.js", ""),
+        ("src/c.js", ""),
+    ]);
+    let root = dir.path();
+    let file = root.join("src/app.ts");
+    let content = fs::read_to_string(&file).unwrap();
+    assert_eq!(
+        names(&resolve_imports(&file, &content, root), root),
+        vec![
+            "src/a.ts",
+            "src/style.css",
+            "src/lib/index.ts",
+            "shared.js",
+            "src/c.js"
+        ]
+    );
+}
+
+#[test]
+fn resolve_ruby_require_relative() {
+    let dir = tree(&[
+        (
+            "lib/app.rb",
+            "require 'json'\nrequire_relative 'helper'\nrequire_relative \"gone\"\n",
+        ),
+        ("lib/helper.rb", ""),
+    ]);
+    let root = dir.path();
+    let file = root.join("lib/app.rb");
+    let content = fs::read_to_string(&file).unwrap();
+    assert_eq!(
+        names(&resolve_imports(&file, &content, root), root),
+        vec!["lib/helper.rb"]
+    );
+}
+
+#[test]
+fn unsupported_language_has_no_imports() {
+    let root = Path::new("/tmp");
+    assert!(resolve_imports(Path::new("/tmp/x.txt"), "import a", root).is_empty());
+}
+
+#[test]
+fn follow_imports_respects_depth_and_cycles() {
+    let dir = tree(&[
+        ("main.py", "import a\n"),
+        ("a.py", "import b\nimport main\ndef from_a():\n    pass\n"),
+        ("b.py", "import a\ndef from_b():\n    pass\n"),
+    ]);
+    let main = dir.path().join("main.py");
+    let content = fs::read_to_string(&main).unwrap();
+
+    let direct = follow_imports(&main, &content, 1);
+    assert_eq!(direct.len(), 1);
+    assert_eq!((direct[0].path.as_str(), direct[0].depth), ("a.py", 1));
+    assert_eq!(direct[0].items[0].text, "def from_a()");
+
+    let deep = follow_imports(&main, &content, 5);
+    let paths: Vec<_> = deep.iter().map(|i| (i.path.as_str(), i.depth)).collect();
+    assert_eq!(paths, vec![("a.py", 1), ("b.py", 2)]);
+}
+
+#[test]
+fn follow_imports_depth_zero_is_empty() {
+    let dir = tree(&[("main.py", "import a\n"), ("a.py", "")]);
+    let main = dir.path().join("main.py");
+    assert!(follow_imports(&main, "import a\n", 0).is_empty());
+}
@@ -0,0 +1,123 @@
+use regex::Regex;
+
+use super::types::{FileOutline, ItemMetrics};
+
+/// Attach size, complexity and TODO metrics to every outline item.
+///
+/// An item spans from its own line up to the line before the next item at
+/// the same or a shallower level (or end of file), minus trailing blanks.
+pub fn attach_metrics(outline: &mut FileOutline, content: &str) {
+    let lines: Vec<&str> = content.lines().collect();
+    let branch_re = Regex::new(
+        r"\b(if|elif|for|while|loop|match|case|when|catch|rescue|unless|until)\b|&&|\|\|",
+    )
+    .expect("invariant: branch regex is valid");
+    let todo_re = Regex::new(r"\b(TODO|FIXME)\b").expect("invariant: todo regex is valid");
+
+    let spans: Vec<(usize, usize)> = (0..outline.items.len())
+        .map(|i| item_span(outline, i, &lines))
+        .collect();
+
+    for (item, (start, end)) in outline.items.iter_mut().zip(spans) {
+        let body = &lines[start - 1..end];
+        item.metrics = Some(ItemMetrics {
+            lines: end + 1 - start,
+            complexity: 1 + body
+                .iter()
+                .map(|l| branch_re.find_iter(l).count())
+                .sum::<usize>(),
+            todos: body.iter().map(|l| todo_re.find_iter(l).count()).sum(),
+        });
+    }
+}
+
+/// Compute the (start, end) 1-indexed inclusive line span of item `idx`
+fn item_span(outline: &FileOutline, idx: usize, lines: &[&str]) -> (usize, usize) {
+    let item = &outline.items[idx];
+    let start = item.line.clamp(1, lines.len().max(1));
+
+    let next = outline.items[idx + 1..]
+        .iter()
+        .find(|other| other.level <= item.level)
+        .map_or(lines.len() + 1, |other| other.line);
+
+    let mut end = next.saturating_sub(1).clamp(start, lines.len().max(start));
+    while end > start && lines.get(end - 1).is_some_and(|l| l.trim().is_empty()) {
+        end -= 1;
+    }
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::outline::extract_outline;
+
+    const RUST: &str = "\
+fn simple() {
+    println!(\"hi\");
+}
+
+fn branchy(x: i32) -> i32 {
+    // TODO: tidy this up
+    if x > 0 && x < 10 {
+        return 1;
+    }
+    for i in 0..x {
+        match i {
+            _ => {}
+        }
+    }
+    0
+}
+";
+
+    fn outline_with_metrics(content: &str, path: &str) -> FileOutline {
+        let mut outline = extract_outline(content, path);
+        attach_metrics(&mut outline, content);
+        outline
+    }
+
+    #[test]
+    fn simple_function_metrics() {
+        let outline = outline_with_metrics(RUST, "test.rs");
+        let metrics = outline.items[0].metrics.as_ref().unwrap();
+        assert_eq!(metrics.lines, 3);
+        assert_eq!(metrics.complexity, 1);
+        assert_eq!(metrics.todos, 0);
+    }
+
+    #[test]
+    fn branchy_function_metrics() {
+        let outline = outline_with_metrics(RUST, "test.rs");
+        let metrics = outline.items[1].metrics.as_ref().unwrap();
+        assert_eq!(metrics.lines, 12);
+        // if, &&, for, match
+        assert_eq!(metrics.complexity, 5);
+        assert_eq!(metrics.todos, 1);
+    }
+
+    #[test]
+    fn nested_items_end_at_next_sibling() {
+        let content = "impl Foo {\n    fn a() {}\n    fn b() {}\n}\nfn c() {}\n";
+        let outline = outline_with_metrics(content, "test.rs");
+        assert_eq!(outline.items[0].metrics.as_ref().unwrap().lines, 4);
+        assert_eq!(outline.items[1].metrics.as_ref().unwrap().lines, 1);
+        assert_eq!(outline.items[2].metrics.as_ref().unwrap().lines, 2);
+    }
+
+    #[test]
+    fn python_branch_keywords() {
+        let content = "def f(x):\n    if x:\n        pass\n    elif y:\n        pass\n";
+        let outline = outline_with_metrics(content, "test.py");
+        assert_eq!(outline.items[0].metrics.as_ref().unwrap().complexity, 3);
+    }
+
+    #[test]
+    fn empty_outline_is_noop() {
+        let mut outline = FileOutline::new();
+        attach_metrics(&mut outline, "");
+        assert!(outline.is_empty());
+    }
+}
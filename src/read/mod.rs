@@ -2,21 +2,40 @@ mod around;
 mod cli;
 mod diff;
 mod display;
+mod encoding;
+mod imports;
 mod interface;
+mod metrics;
 mod outline;
 mod service;
 mod types;
 
-pub use cli::ReadArgs;
+pub use cli::{ReadArgs, ReadFormat};
+pub use encoding::Encoding;
 pub use types::ReadOutput;
 
 use anyhow::Result;
 
+use crate::util::{redact, timings};
+
 /// Run the read command (CLI entry point - formats and prints)
 #[cfg(not(tarpaulin_include))]
 pub fn run(args: ReadArgs) -> Result<()> {
-    let output = service::run(args)?;
-    let formatted = display::format(&output);
+    let format = args.format;
+    let redact = args.redact || redact::enabled_by_default();
+    let output = {
+        let _span = timings::span("parse: read");
+        service::run(args)?
+    };
+    let _span = timings::span("render");
+    let mut formatted = display::render(&output, format);
+    if redact {
+        let (redacted, count) = redact::redact(&formatted);
+        if count > 0 {
+            eprintln!("Redacted {} secret(s)", count);
+        }
+        formatted = redacted;
+    }
     print!("{}", formatted);
     Ok(())
 }
@@ -5,16 +5,51 @@ use std::path::Path;
 use super::around::extract_lines_around;
 use super::cli::ReadArgs;
 use super::diff::git_diff;
+use super::encoding::{decode, detect, Encoding};
+use super::imports::follow_imports;
 use super::interface::extract_interface;
+use super::metrics::attach_metrics;
 use super::outline::extract_outline;
 use super::types::ReadOutput;
 
 /// Run the read command - returns data, never prints
 pub fn run(args: ReadArgs) -> Result<ReadOutput> {
     let path = resolve_path(&args.path)?;
-    let content = fs::read_to_string(&path)
-        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+    let bytes =
+        fs::read(&path).with_context(|| format!("Failed to read file: {}", path.display()))?;
+    let encoding = match args.encoding {
+        Some(encoding) => encoding,
+        None => detect(&bytes).with_context(|| {
+            format!(
+                "{} looks like a binary file (pass --encoding to read it anyway)",
+                path.display()
+            )
+        })?,
+    };
+    let content = decode(&bytes, encoding);
 
+    let imports = args
+        .follow_imports
+        .map(|depth| follow_imports(&path, &content, depth));
+    let mut output = build_output(&args, &path, content)?;
+    if let Some(imports) = imports {
+        output = ReadOutput::WithImports {
+            output: Box::new(output),
+            imports,
+        };
+    }
+    if encoding == Encoding::Utf8 {
+        Ok(output)
+    } else {
+        Ok(ReadOutput::Decoded {
+            encoding,
+            output: Box::new(output),
+        })
+    }
+}
+
+/// Produce the requested view of already-decoded file content
+fn build_output(args: &ReadArgs, path: &Path, content: String) -> Result<ReadOutput> {
     if let Some(center) = args.around {
         // Lines around a specific line
         let (lines, total_lines) = extract_lines_around(&content, center, args.context);
@@ -38,7 +73,10 @@ pub fn run(args: ReadArgs) -> Result<ReadOutput> {
         Ok(ReadOutput::Interface(items))
     } else if args.outline {
         // File outline
-        let outline = extract_outline(&content, path.to_str().unwrap_or(""));
+        let mut outline = extract_outline(&content, path.to_str().unwrap_or(""));
+        if args.metrics {
+            attach_metrics(&mut outline, &content);
+        }
         Ok(ReadOutput::Outline(outline))
     } else {
         // Full file content
@@ -64,6 +102,7 @@ fn resolve_path(path_str: &str) -> Result<std::path::PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::read::cli::ReadFormat;
 
     #[test]
     fn resolve_path_absolute() {
@@ -90,26 +129,58 @@ mod tests {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
             outline: true,
+            metrics: false,
             interface: false,
             around: None,
             context: 10,
             diff: false,
             commit: "HEAD".to_string(),
+            follow_imports: None,
+            encoding: None,
+            format: ReadFormat::Text,
+            redact: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Outline(_)));
     }
 
+    #[test]
+    fn run_outline_with_metrics() {
+        let args = ReadArgs {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/src/main.rs").to_string(),
+            outline: true,
+            metrics: true,
+            interface: false,
+            around: None,
+            context: 10,
+            diff: false,
+            commit: "HEAD".to_string(),
+            follow_imports: None,
+            encoding: None,
+            format: ReadFormat::Text,
+            redact: false,
+        };
+        let ReadOutput::Outline(outline) = run(args).unwrap() else {
+            panic!("Expected Outline");
+        };
+        assert!(outline.items.iter().all(|i| i.metrics.is_some()));
+    }
+
     #[test]
     fn run_returns_around() {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
             outline: false,
+            metrics: false,
             interface: false,
             around: Some(5),
             context: 3,
             diff: false,
             commit: "HEAD".to_string(),
+            follow_imports: None,
+            encoding: None,
+            format: ReadFormat::Text,
+            redact: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Around { .. }));
@@ -120,11 +191,16 @@ mod tests {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
             outline: false,
+            metrics: false,
             interface: false,
             around: None,
             context: 10,
             diff: false,
             commit: "HEAD".to_string(),
+            follow_imports: None,
+            encoding: None,
+            format: ReadFormat::Text,
+            redact: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Full(_)));
@@ -135,11 +211,16 @@ mod tests {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/src/main.rs").to_string(),
             outline: false,
+            metrics: false,
             interface: true,
             around: None,
             context: 10,
             diff: false,
             commit: "HEAD".to_string(),
+            follow_imports: None,
+            encoding: None,
+            format: ReadFormat::Text,
+            redact: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Interface(_)));
@@ -150,11 +231,16 @@ mod tests {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
             outline: false,
+            metrics: false,
             interface: false,
             around: None,
             context: 10,
             diff: true,
             commit: "HEAD".to_string(),
+            follow_imports: None,
+            encoding: None,
+            format: ReadFormat::Text,
+            redact: false,
         };
         let result = run(args).unwrap();
         assert!(matches!(result, ReadOutput::Diff(_)));
@@ -165,13 +251,108 @@ mod tests {
         let args = ReadArgs {
             path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
             outline: false,
+            metrics: false,
             interface: false,
             around: None,
             context: 10,
             diff: true,
             commit: "HEAD~1".to_string(),
+            follow_imports: None,
+            encoding: None,
+            format: ReadFormat::Text,
+            redact: false,
         };
         // This may fail if HEAD~1 doesn't exist, but shouldn't panic
         let _ = run(args);
     }
+
+    fn full_read_args(path: &Path, encoding: Option<Encoding>) -> ReadArgs {
+        ReadArgs {
+            path: path.to_string_lossy().to_string(),
+            outline: false,
+            metrics: false,
+            interface: false,
+            around: None,
+            context: 10,
+            diff: false,
+            commit: "HEAD".to_string(),
+            follow_imports: None,
+            encoding,
+            format: ReadFormat::Text,
+            redact: false,
+        }
+    }
+
+    #[test]
+    fn run_detects_latin1() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("latin1.txt");
+        fs::write(&path, b"caf\xE9\n").unwrap();
+
+        let ReadOutput::Decoded { encoding, output } = run(full_read_args(&path, None)).unwrap()
+        else {
+            panic!("expected decoded output");
+        };
+        assert_eq!(encoding, Encoding::Latin1);
+        assert!(matches!(*output, ReadOutput::Full(ref s) if s == "café\n"));
+    }
+
+    #[test]
+    fn run_rejects_binary_unless_encoding_given() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logo.png");
+        fs::write(
+            &path,
+            b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\x01\0\0\0\x01\0\x08\x06",
+        )
+        .unwrap();
+
+        let err = run(full_read_args(&path, None)).unwrap_err().to_string();
+        assert!(err.contains("binary"), "{err}");
+        assert!(run(full_read_args(&path, Some(Encoding::Latin1))).is_ok());
+    }
+
+    #[test]
+    fn run_encoding_override() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plain.txt");
+        fs::write(&path, b"abc").unwrap();
+
+        let result = run(full_read_args(&path, Some(Encoding::Latin1))).unwrap();
+        assert!(matches!(
+            result,
+            ReadOutput::Decoded {
+                encoding: Encoding::Latin1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn run_follow_imports_appends_interfaces() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("util.py"), "def helper():\n    pass\n").unwrap();
+        let path = dir.path().join("main.py");
+        fs::write(&path, "import util\n\nhelper()\n").unwrap();
+
+        let mut args = full_read_args(&path, None);
+        args.follow_imports = Some(1);
+        let ReadOutput::WithImports { output, imports } = run(args).unwrap() else {
+            panic!("expected output with imports");
+        };
+        assert!(matches!(*output, ReadOutput::Full(_)));
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].path, "util.py");
+        assert_eq!(imports[0].items[0].text, "def helper()");
+    }
+
+    #[test]
+    fn run_utf8_is_not_wrapped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("utf8.txt");
+        fs::write(&path, "héllo").unwrap();
+
+        let result = run(full_read_args(&path, None)).unwrap();
+        assert!(matches!(result, ReadOutput::Full(ref s) if s == "héllo"));
+    }
 }
@@ -1,5 +1,9 @@
+use serde::Serialize;
+
+use super::encoding::Encoding;
+
 /// An item in a file outline (function, struct, class, heading, etc.)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct OutlineItem {
     /// Line number where this item starts (1-indexed)
     pub line: usize,
@@ -9,6 +13,9 @@ pub struct OutlineItem {
     pub level: usize,
     /// Kind of item (function, struct, class, heading, etc.)
     pub kind: ItemKind,
+    /// Size and complexity metrics (only computed with --metrics)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<ItemMetrics>,
 }
 
 impl OutlineItem {
@@ -18,12 +25,25 @@ impl OutlineItem {
             text,
             level,
             kind,
+            metrics: None,
         }
     }
 }
 
+/// Code-health metrics for an outline item
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ItemMetrics {
+    /// Length of the item in lines
+    pub lines: usize,
+    /// Rough cyclomatic complexity (1 + branch keyword count)
+    pub complexity: usize,
+    /// Number of TODO/FIXME markers inside the item
+    pub todos: usize,
+}
+
 /// Kind of outline item
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ItemKind {
     Function,
     Struct,
@@ -62,7 +82,7 @@ impl ItemKind {
 }
 
 /// File outline (collection of items)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct FileOutline {
     pub items: Vec<OutlineItem>,
 }
@@ -86,8 +106,20 @@ impl FileOutline {
     }
 }
 
+/// Public interface of a file pulled in by --follow-imports
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedInterface {
+    /// Path relative to the project root
+    pub path: String,
+    /// Import hops from the file being read (1 = direct import)
+    pub depth: usize,
+    /// Public items of the imported file
+    pub items: Vec<OutlineItem>,
+}
+
 /// Result of reading a file with options
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "mode", content = "data", rename_all = "snake_case")]
 pub enum ReadOutput {
     /// Full file content
     Full(String),
@@ -103,6 +135,16 @@ pub enum ReadOutput {
     },
     /// Git diff output
     Diff(String),
+    /// Output followed by the public interfaces of the files it imports
+    WithImports {
+        output: Box<ReadOutput>,
+        imports: Vec<ImportedInterface>,
+    },
+    /// Output of a file that was converted to UTF-8 from another encoding
+    Decoded {
+        encoding: Encoding,
+        output: Box<ReadOutput>,
+    },
 }
 
 #[cfg(test)]
@@ -116,6 +158,7 @@ mod tests {
         assert_eq!(item.text, "fn test()");
         assert_eq!(item.level, 0);
         assert_eq!(item.kind, ItemKind::Function);
+        assert!(item.metrics.is_none());
     }
 
     #[test]
@@ -10,7 +10,7 @@ pub enum ShellCommand {
 
 #[derive(Debug, Args)]
 pub struct LsArgs {
-    /// Arguments passed through to GNU ls
+    /// Arguments passed through to GNU ls (or `--tree [-L depth] [--no-icons]`)
     #[arg(trailing_var_arg = true, allow_hyphen_values = true, num_args = 0..)]
     pub args: Vec<String>,
 }
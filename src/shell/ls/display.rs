@@ -110,7 +110,7 @@ fn parse_name_and_type(name: &str) -> (&str, FileType) {
 }
 
 /// Get the crossterm color for a given file type and name.
-fn color_for_type(name: &str, file_type: FileType) -> Color {
+pub fn color_for_type(name: &str, file_type: FileType) -> Color {
     match file_type {
         FileType::Directory => FILE_COLORS.directory(),
         FileType::Symlink => FILE_COLORS.symlink(),
@@ -130,7 +130,7 @@ fn color_for_type(name: &str, file_type: FileType) -> Color {
 }
 
 /// Apply color and attributes via crossterm Stylize.
-fn colorize(text: &str, color: Color, file_type: FileType) -> String {
+pub fn colorize(text: &str, color: Color, file_type: FileType) -> String {
     let styled = text.with(color);
     match file_type {
         FileType::Directory | FileType::Executable => styled.attribute(Attribute::Bold).to_string(),
@@ -1,12 +1,20 @@
 mod colors;
 mod display;
 mod service;
+mod tree;
 mod types;
 
 use crate::shell::cli::LsArgs;
 use anyhow::Result;
 
 pub fn run(args: LsArgs) -> Result<()> {
+    // --tree renders natively instead of shelling out to GNU ls
+    if let Some(opts) = tree::parse_tree_args(&args.args)? {
+        let root = tree::build_tree(&opts)?;
+        println!("{}", tree::render_tree(&root, opts.icons));
+        return Ok(());
+    }
+
     // We always inject -1 (one per line) when NOT in long mode and user did
     // not request a specific column format, so we get parseable output.
     let is_long = service::has_long_flag(&args.args);
@@ -101,6 +109,22 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn run_tree_mode() {
+        let args = LsArgs {
+            args: vec!["--tree".to_string(), "-L".to_string(), "1".to_string()],
+        };
+        assert!(run(args).is_ok());
+    }
+
+    #[test]
+    fn run_tree_not_a_directory() {
+        let args = LsArgs {
+            args: vec!["--tree".to_string(), "/nonexistent/xyz123".to_string()],
+        };
+        assert!(run(args).is_err());
+    }
+
     #[test]
     fn run_single_column_explicit() {
         let args = LsArgs {
@@ -0,0 +1,255 @@
+use anyhow::{bail, Context, Result};
+use crossterm::style::{Color, Stylize};
+use std::fs;
+use std::io::ErrorKind;
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::path::Path;
+
+use super::display::{color_for_type, colorize};
+use super::types::{icon_for_entry, FileType};
+
+#[cfg(test)]
+mod tests;
+
+/// Options for `hu shell ls --tree`, parsed from the passthrough args.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeOptions {
+    /// Root directory to render
+    pub path: String,
+    /// Maximum depth below the root (`-L`), unlimited when None
+    pub depth: Option<usize>,
+    /// Show Nerd Font icons
+    pub icons: bool,
+    /// Include hidden entries (`-a` / `--all`)
+    pub all: bool,
+}
+
+/// A node in the rendered directory tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode {
+    pub name: String,
+    pub file_type: FileType,
+    pub children: Vec<TreeNode>,
+    /// True when the directory was not descended into (depth limit)
+    pub truncated: bool,
+    /// True when the directory could not be read (EACCES)
+    pub denied: bool,
+}
+
+impl TreeNode {
+    /// Count (directories, files) in this subtree, excluding the node itself.
+    pub fn counts(&self) -> (usize, usize) {
+        self.children.iter().fold((0, 0), |(dirs, files), child| {
+            let (d, f) = child.counts();
+            if child.file_type == FileType::Directory {
+                (dirs + d + 1, files + f)
+            } else {
+                (dirs + d, files + f + 1)
+            }
+        })
+    }
+}
+
+/// Extract tree options from ls args. Returns None unless `--tree` is present,
+/// so `-L` keeps its GNU ls meaning (dereference) outside tree mode.
+pub fn parse_tree_args(args: &[String]) -> Result<Option<TreeOptions>> {
+    if !args.iter().any(|a| a == "--tree") {
+        return Ok(None);
+    }
+
+    let mut opts = TreeOptions {
+        path: ".".to_string(),
+        depth: None,
+        icons: true,
+        all: false,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--tree" => {}
+            "--no-icons" => opts.icons = false,
+            "-a" | "--all" | "-A" | "--almost-all" => opts.all = true,
+            "-L" | "--level" => {
+                let value = iter.next().context("-L requires a depth")?;
+                opts.depth = Some(parse_depth(value)?);
+            }
+            other if other.starts_with("--level=") => {
+                opts.depth = Some(parse_depth(&other["--level=".len()..])?);
+            }
+            other if other.starts_with('-') => {
+                bail!("Unsupported option in --tree mode: {}", other)
+            }
+            other => opts.path = other.to_string(),
+        }
+    }
+
+    Ok(Some(opts))
+}
+
+fn parse_depth(value: &str) -> Result<usize> {
+    match value.parse::<usize>() {
+        Ok(depth) if depth > 0 => Ok(depth),
+        _ => bail!("Invalid depth '{}': must be a positive integer", value),
+    }
+}
+
+/// Build a tree by walking the filesystem from `opts.path`.
+pub fn build_tree(opts: &TreeOptions) -> Result<TreeNode> {
+    let root = Path::new(&opts.path);
+    if !root.is_dir() {
+        bail!("Not a directory: {}", opts.path);
+    }
+    let mut node = TreeNode {
+        name: opts.path.clone(),
+        file_type: FileType::Directory,
+        children: vec![],
+        truncated: false,
+        denied: false,
+    };
+    let entries =
+        fs::read_dir(root).with_context(|| format!("Failed to read directory: {:?}", root))?;
+    node.children = read_children(entries, 1, opts)?;
+    Ok(node)
+}
+
+fn read_children(entries: fs::ReadDir, depth: usize, opts: &TreeOptions) -> Result<Vec<TreeNode>> {
+    let mut children = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !opts.all && name.starts_with('.') {
+            continue;
+        }
+
+        let file_type = detect_file_type(&entry.path());
+        let mut child = TreeNode {
+            name,
+            file_type,
+            children: vec![],
+            truncated: false,
+            denied: false,
+        };
+
+        if file_type == FileType::Directory {
+            if opts.depth.is_some_and(|max| depth >= max) {
+                child.truncated = true;
+            } else {
+                // An unreadable subdirectory is marked, not fatal
+                match fs::read_dir(entry.path()) {
+                    Ok(sub) => child.children = read_children(sub, depth + 1, opts)?,
+                    Err(e) if e.kind() == ErrorKind::PermissionDenied => child.denied = true,
+                    Err(e) => {
+                        return Err(e).with_context(|| {
+                            format!("Failed to read directory: {:?}", entry.path())
+                        })
+                    }
+                }
+            }
+        }
+        children.push(child);
+    }
+
+    sort_entries(&mut children);
+    Ok(children)
+}
+
+/// Directories first, then case-insensitive by name (like --group-directories-first).
+fn sort_entries(nodes: &mut [TreeNode]) {
+    nodes.sort_by(|a, b| {
+        let a_dir = a.file_type == FileType::Directory;
+        let b_dir = b.file_type == FileType::Directory;
+        b_dir
+            .cmp(&a_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+}
+
+/// Map filesystem metadata to the same FileType that `ls --classify` reports.
+fn detect_file_type(path: &Path) -> FileType {
+    let Ok(meta) = fs::symlink_metadata(path) else {
+        return FileType::Regular;
+    };
+    let ft = meta.file_type();
+
+    if ft.is_symlink() {
+        FileType::Symlink
+    } else if ft.is_dir() {
+        FileType::Directory
+    } else if ft.is_fifo() {
+        FileType::Pipe
+    } else if ft.is_socket() {
+        FileType::Socket
+    } else if meta.permissions().mode() & 0o111 != 0 {
+        FileType::Executable
+    } else {
+        FileType::Regular
+    }
+}
+
+/// Render a tree with box-drawing connectors, colors and a totals line.
+pub fn render_tree(root: &TreeNode, icons: bool) -> String {
+    let mut lines = vec![format_entry(root, icons)];
+    render_children(&root.children, "", icons, &mut lines);
+
+    let (dirs, files) = root.counts();
+    lines.push(String::new());
+    lines.push(format!(
+        "{} {}, {} {}",
+        dirs,
+        if dirs == 1 {
+            "directory"
+        } else {
+            "directories"
+        },
+        files,
+        if files == 1 { "file" } else { "files" }
+    ));
+    lines.join("\n")
+}
+
+fn render_children(children: &[TreeNode], prefix: &str, icons: bool, lines: &mut Vec<String>) {
+    for (i, child) in children.iter().enumerate() {
+        let is_last = i + 1 == children.len();
+        let connector = if is_last { "└── " } else { "├── " };
+        lines.push(format!(
+            "{}{}{}",
+            prefix,
+            connector.with(Color::DarkGrey),
+            format_entry(child, icons)
+        ));
+
+        let extension = if is_last { "    " } else { "│   " };
+        render_children(
+            &child.children,
+            &format!("{}{}", prefix, extension.with(Color::DarkGrey)),
+            icons,
+            lines,
+        );
+    }
+}
+
+/// Format one entry: optional icon, colored name, per-directory counts.
+fn format_entry(node: &TreeNode, icons: bool) -> String {
+    let color = color_for_type(&node.name, node.file_type);
+    let mut out = colorize(&node.name, color, node.file_type);
+
+    if icons {
+        out = format!("{} {}", icon_for_entry(&node.name, node.file_type), out);
+    }
+
+    if node.file_type == FileType::Directory && !node.truncated && !node.children.is_empty() {
+        let dirs = node
+            .children
+            .iter()
+            .filter(|c| c.file_type == FileType::Directory)
+            .count();
+        let files = node.children.len() - dirs;
+        let counts = format!(" ({}d {}f)", dirs, files);
+        out.push_str(&counts.with(Color::DarkGrey).to_string());
+    }
+    if node.denied {
+        out.push_str(&" [permission denied]".with(Color::Red).to_string());
+    }
+
+    out
+}
@@ -0,0 +1,191 @@
+use super::*;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+fn leaf(name: &str, file_type: FileType) -> TreeNode {
+    TreeNode {
+        name: name.to_string(),
+        file_type,
+        children: vec![],
+        truncated: false,
+        denied: false,
+    }
+}
+
+fn sample_tree() -> TreeNode {
+    let mut src = leaf("src", FileType::Directory);
+    src.children = vec![leaf("main.rs", FileType::Regular)];
+    let mut root = leaf(".", FileType::Directory);
+    root.children = vec![src, leaf("Cargo.toml", FileType::Regular)];
+    root
+}
+
+#[test]
+fn parse_without_tree_flag() {
+    assert_eq!(parse_tree_args(&args(&["-L", "/tmp"])).unwrap(), None);
+}
+
+#[test]
+fn parse_tree_defaults() {
+    let opts = parse_tree_args(&args(&["--tree"])).unwrap().unwrap();
+    assert_eq!(opts.path, ".");
+    assert_eq!(opts.depth, None);
+    assert!(opts.icons);
+    assert!(!opts.all);
+}
+
+#[test]
+fn parse_tree_full() {
+    let opts = parse_tree_args(&args(&["--tree", "-L", "2", "-a", "--no-icons", "src"]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(opts.path, "src");
+    assert_eq!(opts.depth, Some(2));
+    assert!(!opts.icons);
+    assert!(opts.all);
+
+    let opts = parse_tree_args(&args(&["--tree", "--level=3"]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(opts.depth, Some(3));
+}
+
+#[test]
+fn parse_tree_invalid_depth() {
+    assert!(parse_tree_args(&args(&["--tree", "-L", "0"])).is_err());
+    assert!(parse_tree_args(&args(&["--tree", "-L", "x"])).is_err());
+    assert!(parse_tree_args(&args(&["--tree", "-L"])).is_err());
+}
+
+#[test]
+fn parse_tree_rejects_other_ls_flags() {
+    assert!(parse_tree_args(&args(&["--tree", "-l"])).is_err());
+}
+
+#[test]
+fn counts_subtree() {
+    assert_eq!(sample_tree().counts(), (1, 2));
+}
+
+#[test]
+fn render_connectors_and_totals() {
+    let out = render_tree(&sample_tree(), false);
+    let lines: Vec<&str> = out.lines().collect();
+    assert!(lines[1].contains("├── "));
+    assert!(lines[1].contains("src"));
+    assert!(lines[2].contains("│   "));
+    assert!(lines[2].contains("└── "));
+    assert!(lines[2].contains("main.rs"));
+    assert!(lines[3].contains("└── "));
+    assert!(lines[3].contains("Cargo.toml"));
+    assert_eq!(*lines.last().unwrap(), "1 directory, 2 files");
+}
+
+#[test]
+fn render_directory_counts() {
+    let out = render_tree(&sample_tree(), false);
+    assert!(out.contains("(1d 1f)"));
+    assert!(out.contains("(0d 1f)"));
+}
+
+#[test]
+fn render_with_icons() {
+    let out = render_tree(&sample_tree(), true);
+    assert!(out.contains('\u{e7a8}')); // Rust icon
+    assert!(out.contains('\u{f115}')); // Directory icon
+}
+
+#[test]
+fn render_truncated_dir_has_no_counts() {
+    let mut root = leaf(".", FileType::Directory);
+    let mut deep = leaf("deep", FileType::Directory);
+    deep.truncated = true;
+    root.children = vec![deep];
+    let out = render_tree(&root, false);
+    assert!(!out.lines().nth(1).unwrap().contains('('));
+}
+
+#[test]
+fn sort_dirs_first() {
+    let mut nodes = vec![
+        leaf("b.rs", FileType::Regular),
+        leaf("zeta", FileType::Directory),
+        leaf("A.rs", FileType::Regular),
+    ];
+    sort_entries(&mut nodes);
+    let names: Vec<_> = nodes.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(names, vec!["zeta", "A.rs", "b.rs"]);
+}
+
+#[test]
+fn build_tree_respects_depth_and_hidden() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("a/b")).unwrap();
+    fs::write(dir.path().join("a/b/deep.txt"), "").unwrap();
+    fs::write(dir.path().join("a/top.txt"), "").unwrap();
+    fs::write(dir.path().join(".hidden"), "").unwrap();
+
+    let opts = TreeOptions {
+        path: dir.path().to_string_lossy().to_string(),
+        depth: Some(2),
+        icons: false,
+        all: false,
+    };
+    let tree = build_tree(&opts).unwrap();
+    assert_eq!(tree.children.len(), 1);
+    let a = &tree.children[0];
+    assert_eq!(a.name, "a");
+    let b = &a.children[0];
+    assert_eq!(b.name, "b");
+    assert!(b.truncated);
+    assert!(b.children.is_empty());
+}
+
+#[test]
+fn build_tree_detects_executables() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("run.sh");
+    fs::write(&script, "#!/bin/sh").unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+    assert_eq!(detect_file_type(&script), FileType::Executable);
+    assert_eq!(detect_file_type(dir.path()), FileType::Directory);
+}
+
+#[test]
+fn render_denied_dir_marker() {
+    let mut root = leaf(".", FileType::Directory);
+    let mut locked = leaf("locked", FileType::Directory);
+    locked.denied = true;
+    root.children = vec![locked, leaf("a.txt", FileType::Regular)];
+    let out = render_tree(&root, false);
+    assert!(out.lines().nth(1).unwrap().contains("[permission denied]"));
+    assert!(out.contains("a.txt"));
+}
+
+#[test]
+fn build_tree_continues_past_unreadable_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let locked = dir.path().join("locked");
+    fs::create_dir_all(locked.join("inner")).unwrap();
+    fs::write(dir.path().join("ok.txt"), "").unwrap();
+    fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+    // root ignores directory permissions, so there is nothing to observe
+    let readable = fs::read_dir(&locked).is_ok();
+
+    let opts = TreeOptions {
+        path: dir.path().to_string_lossy().to_string(),
+        depth: None,
+        icons: false,
+        all: false,
+    };
+    let tree = build_tree(&opts);
+    fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+    let tree = tree.unwrap();
+
+    let names: Vec<_> = tree.children.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["locked", "ok.txt"]);
+    assert_eq!(tree.children[0].denied, !readable);
+}
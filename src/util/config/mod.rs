@@ -1,8 +1,11 @@
 use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use super::timings;
+
 #[cfg(test)]
 mod tests;
 
@@ -48,8 +51,50 @@ fn credentials_path() -> Result<PathBuf> {
     Ok(config_dir()?.join("credentials.toml"))
 }
 
+/// Returns the path to `~/.config/hu/settings.toml`
+pub fn settings_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|p| p.join(".config").join("hu").join("settings.toml"))
+}
+
+/// Load the `[section]` table of settings.toml (`None` when the file or
+/// section is missing)
+#[cfg(not(tarpaulin_include))]
+pub fn load_settings_section<T: DeserializeOwned>(section: &str) -> Result<Option<T>> {
+    let Some(path) = settings_path().filter(|p| p.exists()) else {
+        return Ok(None);
+    };
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    settings_section_from_toml(&contents, section)
+        .with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Like [`load_settings_section`], but a broken settings file only warns so
+/// optional features (redaction, context expiry) never block a command
+#[cfg(not(tarpaulin_include))]
+pub fn load_settings_section_or_warn<T: DeserializeOwned>(section: &str) -> Option<T> {
+    load_settings_section(section).unwrap_or_else(|e| {
+        eprintln!("Warning: ignoring [{}] settings: {:#}", section, e);
+        None
+    })
+}
+
+/// Parse the `[section]` table out of settings TOML
+pub fn settings_section_from_toml<T: DeserializeOwned>(
+    content: &str,
+    section: &str,
+) -> Result<Option<T>> {
+    let mut table: toml::Table = toml::from_str(content)?;
+    table
+        .remove(section)
+        .map(|value| value.try_into::<T>())
+        .transpose()
+        .with_context(|| format!("Invalid [{}] section", section))
+}
+
 /// Load credentials from config dir
 pub fn load_credentials() -> Result<Credentials> {
+    let _span = timings::span("auth: credentials");
     let path = credentials_path()?;
     load_credentials_from(&path)
 }
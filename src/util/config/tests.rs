@@ -506,3 +506,46 @@ fn save_credentials_and_load_integration() {
         save_credentials(&orig).unwrap();
     }
 }
+
+#[test]
+fn settings_path_is_hu_settings_toml() {
+    if let Some(path) = settings_path() {
+        let path_str = path.to_string_lossy();
+        assert!(path_str.contains(".config"));
+        assert!(path_str.ends_with("hu/settings.toml"));
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct SampleSection {
+    name: String,
+}
+
+#[test]
+fn settings_section_from_toml_picks_section() {
+    let toml = "[other]\nx = 1\n\n[sample]\nname = \"hu\"\n";
+    let section: Option<SampleSection> = settings_section_from_toml(toml, "sample").unwrap();
+    assert_eq!(
+        section,
+        Some(SampleSection {
+            name: "hu".to_string()
+        })
+    );
+}
+
+#[test]
+fn settings_section_from_toml_missing_section() {
+    let section: Option<SampleSection> = settings_section_from_toml("", "sample").unwrap();
+    assert!(section.is_none());
+    let section: Option<SampleSection> =
+        settings_section_from_toml("[other]\nx = 1\n", "sample").unwrap();
+    assert!(section.is_none());
+}
+
+#[test]
+fn settings_section_from_toml_errors() {
+    assert!(settings_section_from_toml::<SampleSection>("not toml [", "sample").is_err());
+    let err =
+        settings_section_from_toml::<SampleSection>("[sample]\nname = 1\n", "sample").unwrap_err();
+    assert!(format!("{:#}", err).contains("[sample]"));
+}
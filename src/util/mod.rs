@@ -1,8 +1,17 @@
 mod config;
 mod output;
+pub mod redact;
 pub mod shell;
+pub mod timings;
+pub mod walk;
 
-pub use config::{load_credentials, BraveCredentials};
+pub use config::{
+    load_credentials, load_settings_section, load_settings_section_or_warn, settings_path,
+    BraveCredentials,
+};
+
+#[cfg(test)]
+pub use config::settings_section_from_toml;
 
 #[allow(unused_imports)]
 pub use config::{config_dir, Credentials};
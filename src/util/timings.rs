@@ -0,0 +1,190 @@
+//! Lightweight span recorder behind the global `--timings` flag.
+//!
+//! Modules wrap a phase in a guard, e.g. `let _span = timings::span("api: nerdgraph");`.
+//! Names are `<phase>: <detail>` (auth, api, io, walk, search, parse) or `render`.
+//! Spans cost nothing beyond a mutex check while recording is disabled.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Process-wide recorder used by [`span`]
+static RECORDER: Recorder = Recorder::new();
+
+/// Collects named span durations once enabled
+pub struct Recorder {
+    spans: Mutex<Option<Vec<(&'static str, Duration)>>>,
+}
+
+impl Recorder {
+    pub const fn new() -> Self {
+        Self {
+            spans: Mutex::new(None),
+        }
+    }
+
+    /// Start recording spans
+    pub fn enable(&self) {
+        *self.lock() = Some(Vec::new());
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.lock().is_some()
+    }
+
+    /// Start a span that records its duration when dropped
+    pub fn span(&self, name: &'static str) -> Span<'_> {
+        Span {
+            recorder: self,
+            name,
+            start: self.is_enabled().then(Instant::now),
+        }
+    }
+
+    fn record(&self, name: &'static str, elapsed: Duration) {
+        if let Some(spans) = self.lock().as_mut() {
+            spans.push((name, elapsed));
+        }
+    }
+
+    /// Take all recorded spans, leaving recording enabled
+    pub fn take(&self) -> Vec<(&'static str, Duration)> {
+        self.lock().as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<Vec<(&'static str, Duration)>>> {
+        // A panic mid-record leaves the data usable; timings are best effort
+        self.spans.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Guard returned by [`span`]; records elapsed time on drop
+pub struct Span<'a> {
+    recorder: &'a Recorder,
+    name: &'static str,
+    start: Option<Instant>,
+}
+
+impl Drop for Span<'_> {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            self.recorder.record(self.name, start.elapsed());
+        }
+    }
+}
+
+/// Enable the global recorder (called once for `--timings`)
+pub fn enable() {
+    RECORDER.enable();
+}
+
+/// Time a phase against the global recorder
+pub fn span(name: &'static str) -> Span<'static> {
+    RECORDER.span(name)
+}
+
+/// Format the global breakdown for a command that took `total`
+pub fn report(total: Duration) -> String {
+    format_report(&RECORDER.take(), total)
+}
+
+/// Aggregate spans by name (first-seen order) into an aligned breakdown
+pub fn format_report(spans: &[(&'static str, Duration)], total: Duration) -> String {
+    let mut rows: Vec<(&str, Duration, usize)> = Vec::new();
+    for (name, elapsed) in spans {
+        match rows.iter_mut().find(|(n, _, _)| n == name) {
+            Some(row) => {
+                row.1 += *elapsed;
+                row.2 += 1;
+            }
+            None => rows.push((name, *elapsed, 1)),
+        }
+    }
+
+    let width = rows
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max("total".len());
+    let total_ms = total.as_secs_f64() * 1000.0;
+
+    let mut lines = vec!["Timings:".to_string()];
+    for (name, elapsed, count) in &rows {
+        let ms = elapsed.as_secs_f64() * 1000.0;
+        let pct = if total_ms > 0.0 {
+            ms / total_ms * 100.0
+        } else {
+            0.0
+        };
+        lines.push(format!(
+            "  {:<width$}  {:>9.1}ms  {:>3}x  {:>5.1}%",
+            name,
+            ms,
+            count,
+            pct,
+            width = width
+        ));
+    }
+    lines.push(format!(
+        "  {:<width$}  {:>9.1}ms",
+        "total",
+        total_ms,
+        width = width
+    ));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_recorder_records_nothing() {
+        let recorder = Recorder::new();
+        drop(recorder.span("api"));
+        assert!(recorder.take().is_empty());
+    }
+
+    #[test]
+    fn enabled_recorder_records_spans() {
+        let recorder = Recorder::new();
+        recorder.enable();
+        {
+            let _outer = recorder.span("render");
+            drop(recorder.span("api"));
+        }
+        let spans = recorder.take();
+        let names: Vec<_> = spans.iter().map(|(n, _)| *n).collect();
+        assert_eq!(names, vec!["api", "render"]);
+        assert!(recorder.take().is_empty());
+    }
+
+    #[test]
+    fn report_aggregates_by_name() {
+        let spans = [
+            ("api", Duration::from_millis(30)),
+            ("parse", Duration::from_millis(10)),
+            ("api", Duration::from_millis(20)),
+        ];
+        let report = format_report(&spans, Duration::from_millis(100));
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "Timings:");
+        assert_eq!(lines[1], "  api         50.0ms    2x   50.0%");
+        assert_eq!(lines[2], "  parse       10.0ms    1x   10.0%");
+        assert_eq!(lines[3], "  total      100.0ms");
+    }
+
+    #[test]
+    fn report_zero_total() {
+        let spans = [("api", Duration::ZERO)];
+        let report = format_report(&spans, Duration::ZERO);
+        assert!(report.contains("0.0%"));
+    }
+
+    #[test]
+    fn report_empty() {
+        let report = format_report(&[], Duration::from_millis(5));
+        assert_eq!(report, "Timings:\n  total        5.0ms");
+    }
+}
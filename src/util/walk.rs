@@ -0,0 +1,332 @@
+//! Directory walking with the ignore rules shared by grep and context.
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Check if a directory should be ignored
+pub fn is_ignored_dir(name: &str) -> bool {
+    matches!(
+        name,
+        "node_modules"
+            | "target"
+            | ".git"
+            | ".svn"
+            | ".hg"
+            | "__pycache__"
+            | ".mypy_cache"
+            | ".pytest_cache"
+            | "venv"
+            | ".venv"
+            | "dist"
+            | "build"
+            | ".next"
+            | ".nuxt"
+    )
+}
+
+/// Check if extension indicates binary file
+pub fn is_binary_extension(ext: &str) -> bool {
+    matches!(
+        ext.to_lowercase().as_str(),
+        "png"
+            | "jpg"
+            | "jpeg"
+            | "gif"
+            | "ico"
+            | "webp"
+            | "bmp"
+            | "svg"
+            | "pdf"
+            | "zip"
+            | "tar"
+            | "gz"
+            | "bz2"
+            | "xz"
+            | "7z"
+            | "rar"
+            | "exe"
+            | "dll"
+            | "so"
+            | "dylib"
+            | "a"
+            | "o"
+            | "obj"
+            | "wasm"
+            | "class"
+            | "jar"
+            | "pyc"
+            | "pyo"
+            | "mp3"
+            | "mp4"
+            | "avi"
+            | "mkv"
+            | "mov"
+            | "wav"
+            | "flac"
+            | "ttf"
+            | "otf"
+            | "woff"
+            | "woff2"
+            | "eot"
+            | "sqlite"
+            | "db"
+    )
+}
+
+/// Check if a path string contains glob wildcards
+pub fn has_glob_chars(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Recursively list text files under `root`, skipping hidden entries,
+/// ignored directories and binary files. Results are sorted.
+pub fn walk_files(root: &Path, include_hidden: bool) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_files(root, include_hidden, false, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+/// Like [`walk_files`], but binary files are included too. Results are sorted.
+pub fn walk_all_files(root: &Path, include_hidden: bool) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_files(root, include_hidden, true, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(
+    dir: &Path,
+    include_hidden: bool,
+    include_binary: bool,
+    files: &mut Vec<PathBuf>,
+) -> Result<()> {
+    let entries =
+        fs::read_dir(dir).with_context(|| format!("Failed to read directory: {:?}", dir))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+
+        if !include_hidden && name.starts_with('.') {
+            continue;
+        }
+
+        // file_type() doesn't follow symlinks; symlinked directories are
+        // skipped so a link back up the tree can't recurse forever
+        let is_symlink = entry.file_type().is_ok_and(|t| t.is_symlink());
+        if path.is_dir() {
+            if !is_symlink && !is_ignored_dir(name) {
+                collect_files(&path, include_hidden, include_binary, files)?;
+            }
+            continue;
+        }
+
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if path.is_file() && (include_binary || !is_binary_extension(ext)) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Expand a path glob (`*`, `?`, `**`) into matching files.
+///
+/// The literal prefix of the pattern is walked with [`walk_files`], and the
+/// remainder is matched against each file's path relative to that prefix.
+pub fn expand_glob(pattern: &str) -> Result<Vec<PathBuf>> {
+    let (base, rest) = split_glob(pattern);
+    let base_path = if base.is_empty() {
+        PathBuf::from(".")
+    } else {
+        PathBuf::from(base)
+    };
+
+    if !base_path.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let re = glob_to_regex(rest)?;
+    let files = walk_files(&base_path, false)?
+        .into_iter()
+        .filter(|path| {
+            path.strip_prefix(&base_path)
+                .map(|rel| re.is_match(&rel.to_string_lossy()))
+                .unwrap_or(false)
+        })
+        .collect();
+
+    Ok(files)
+}
+
+/// Split a glob into its literal directory prefix and the wildcard remainder
+fn split_glob(pattern: &str) -> (&str, &str) {
+    let first_wild = pattern.find(['*', '?']).unwrap_or(pattern.len());
+    match pattern[..first_wild].rfind('/') {
+        Some(0) => ("/", &pattern[1..]),
+        Some(idx) => (&pattern[..idx], &pattern[idx + 1..]),
+        None => ("", pattern),
+    }
+}
+
+/// Convert a path glob to an anchored regex (`**` crosses directories)
+fn glob_to_regex(glob: &str) -> Result<Regex> {
+    let mut re = String::from("^");
+    let mut chars = glob.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    re.push_str("(?:.*/)?");
+                } else {
+                    re.push_str(".*");
+                }
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            _ => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    re.push('$');
+
+    Regex::new(&re).with_context(|| format!("Invalid glob pattern: {}", glob))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/nested")).unwrap();
+        fs::create_dir_all(root.join("target")).unwrap();
+        fs::write(root.join("README.md"), "readme").unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(root.join("src/nested/lib.rs"), "pub fn x() {}").unwrap();
+        fs::write(root.join("src/logo.png"), "png").unwrap();
+        fs::write(root.join("src/.hidden.rs"), "").unwrap();
+        fs::write(root.join("target/out.rs"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn ignored_dirs() {
+        assert!(is_ignored_dir("node_modules"));
+        assert!(is_ignored_dir("target"));
+        assert!(!is_ignored_dir("src"));
+    }
+
+    #[test]
+    fn binary_extensions() {
+        assert!(is_binary_extension("PNG"));
+        assert!(is_binary_extension("sqlite"));
+        assert!(!is_binary_extension("rs"));
+    }
+
+    #[test]
+    fn glob_chars_detected() {
+        assert!(has_glob_chars("src/**/*.rs"));
+        assert!(has_glob_chars("file?.txt"));
+        assert!(!has_glob_chars("src/main.rs"));
+    }
+
+    #[test]
+    fn walk_skips_ignored_hidden_and_binary() {
+        let dir = fixture();
+        let files = walk_files(dir.path(), false).unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .map(|p| {
+                p.strip_prefix(dir.path())
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(names, vec!["README.md", "src/main.rs", "src/nested/lib.rs"]);
+    }
+
+    #[test]
+    fn walk_all_includes_binary_but_still_skips_ignored() {
+        let dir = fixture();
+        let files = walk_all_files(dir.path(), false).unwrap();
+        assert!(files.iter().any(|p| p.ends_with("src/logo.png")));
+        assert!(!files.iter().any(|p| p.ends_with("target/out.rs")));
+        assert!(!files.iter().any(|p| p.ends_with(".hidden.rs")));
+    }
+
+    #[test]
+    fn walk_includes_hidden_when_requested() {
+        let dir = fixture();
+        let files = walk_files(dir.path(), true).unwrap();
+        assert!(files.iter().any(|p| p.ends_with(".hidden.rs")));
+    }
+
+    #[test]
+    fn walk_skips_symlinked_dirs() {
+        let dir = fixture();
+        // src/nested/up -> src, a loop if followed
+        std::os::unix::fs::symlink(dir.path().join("src"), dir.path().join("src/nested/up"))
+            .unwrap();
+        let files = walk_files(dir.path(), false).unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(!files.iter().any(|p| p.to_string_lossy().contains("/up/")));
+    }
+
+    #[test]
+    fn walk_missing_dir_errors() {
+        assert!(walk_files(Path::new("/nonexistent/dir/xyz"), false).is_err());
+    }
+
+    #[test]
+    fn split_glob_prefixes() {
+        assert_eq!(split_glob("src/**/*.rs"), ("src", "**/*.rs"));
+        assert_eq!(split_glob("*.rs"), ("", "*.rs"));
+        assert_eq!(split_glob("/tmp/*.rs"), ("/tmp", "*.rs"));
+        assert_eq!(split_glob("/*.rs"), ("/", "*.rs"));
+    }
+
+    #[test]
+    fn glob_regex_single_star_stays_in_dir() {
+        let re = glob_to_regex("*.rs").unwrap();
+        assert!(re.is_match("main.rs"));
+        assert!(!re.is_match("nested/lib.rs"));
+    }
+
+    #[test]
+    fn glob_regex_double_star_crosses_dirs() {
+        let re = glob_to_regex("**/*.rs").unwrap();
+        assert!(re.is_match("main.rs"));
+        assert!(re.is_match("a/b/lib.rs"));
+        assert!(!re.is_match("a/b/lib.py"));
+    }
+
+    #[test]
+    fn glob_regex_question_mark_and_escape() {
+        let re = glob_to_regex("file?.txt").unwrap();
+        assert!(re.is_match("file1.txt"));
+        assert!(!re.is_match("file1xtxt"));
+    }
+
+    #[test]
+    fn expand_glob_recursive() {
+        let dir = fixture();
+        let pattern = format!("{}/src/**/*.rs", dir.path().display());
+        let files = expand_glob(&pattern).unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().all(|p| p.extension().unwrap() == "rs"));
+    }
+
+    #[test]
+    fn expand_glob_missing_base() {
+        let files = expand_glob("/nonexistent/dir/*.rs").unwrap();
+        assert!(files.is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 use clap::{Args, Subcommand};
 
+use super::web_search::parse_domain;
+
 #[derive(Debug, Subcommand)]
 pub enum UtilsCommand {
     /// Fetch URL and convert to markdown
@@ -14,6 +16,8 @@ pub enum UtilsCommand {
     DocsSearch(DocsSearchArgs),
     /// Extract a section from a markdown file
     DocsSection(DocsSectionArgs),
+    /// Generate (or verify) a checksum manifest for a directory
+    Manifest(ManifestArgs),
 }
 
 #[derive(Debug, Args)]
@@ -48,25 +52,67 @@ pub struct FetchHtmlArgs {
     /// Raw output (no filtering)
     #[arg(long, short = 'r')]
     pub raw: bool,
+
+    #[command(flatten)]
+    pub auth: RequestAuthArgs,
+}
+
+/// Authentication, extra headers and cookies for fetching pages.
+/// Debug output redacts secrets (see `request_auth`).
+#[derive(Clone, Default, Args)]
+pub struct RequestAuthArgs {
+    /// Extra request header, repeatable (e.g., "X-Team: infra")
+    #[arg(long = "header", value_name = "K: V")]
+    pub headers: Vec<String>,
+
+    /// HTTP basic auth credentials
+    #[arg(long, value_name = "USER:PASS")]
+    pub basic: Option<String>,
+
+    /// Bearer token sent as Authorization header
+    #[arg(long, value_name = "TOKEN", conflicts_with = "basic")]
+    pub bearer: Option<String>,
+
+    /// Netscape-format cookie file to send cookies from (e.g., SSO session)
+    #[arg(long, value_name = "FILE")]
+    pub cookie_jar: Option<String>,
 }
 
 #[derive(Debug, Args)]
 pub struct GrepArgs {
-    /// Pattern to search for (regex)
-    pub pattern: String,
+    /// Pattern to search for (regex); with -e, this is the path instead
+    #[arg(required_unless_present = "patterns")]
+    pub pattern: Option<String>,
 
     /// Path to search (default: current directory)
     #[arg(default_value = ".")]
     pub path: String,
 
+    /// Pattern to search for, repeatable (lines matching any are shown)
+    #[arg(long = "regexp", short = 'e', value_name = "PATTERN")]
+    pub patterns: Vec<String>,
+
+    /// Require every -e pattern to match the line (AND)
+    #[arg(long, requires = "patterns")]
+    pub all: bool,
+
+    /// Exclude lines matching this pattern, repeatable
+    #[arg(long, value_name = "PATTERN")]
+    pub not: Vec<String>,
+
     /// Return file:line references only (no content)
     #[arg(long)]
     pub refs: bool,
 
-    /// Deduplicate similar matches
-    #[arg(long)]
+    /// Deduplicate similar matches (same as --unique-by content)
+    #[arg(long, conflicts_with = "unique_by")]
     pub unique: bool,
 
+    /// Deduplicate by file (repeated lines within each file; identical lines
+    /// in other files are kept) or content (across files)
+    #[arg(long, value_enum, value_name = "KEY")]
+    pub unique_by: Option<UniqueBy>,
+
     /// Sort by relevance (match density)
     #[arg(long)]
     pub ranked: bool,
@@ -90,6 +136,28 @@ pub struct GrepArgs {
     /// Include hidden files
     #[arg(long)]
     pub hidden: bool,
+
+    /// Print a summary: files scanned/matched, total matches, top files, time
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Output as JSON
+    #[arg(long, short = 'j')]
+    pub json: bool,
+
+    /// Replace secrets (keys, tokens, passwords) in matched lines with placeholders
+    #[arg(long)]
+    pub redact: bool,
+}
+
+/// Dedupe key for `grep --unique-by`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UniqueBy {
+    /// First occurrence of each line within each file (keyed on file and line)
+    #[value(alias = "both")]
+    File,
+    /// First occurrence of each line across all files
+    Content,
 }
 
 #[derive(Debug, Args)]
@@ -108,6 +176,35 @@ pub struct WebSearchArgs {
     /// Output to file instead of stdout
     #[arg(long, short = 'o')]
     pub output: Option<String>,
+
+    /// Only return results from this domain (repeatable)
+    #[arg(long, value_name = "DOMAIN", value_parser = parse_domain)]
+    pub site: Vec<String>,
+
+    /// Drop results from this domain (repeatable)
+    #[arg(long, value_name = "DOMAIN", value_parser = parse_domain)]
+    pub exclude_site: Vec<String>,
+
+    /// Only return pages published within this period
+    #[arg(long, value_enum)]
+    pub freshness: Option<Freshness>,
+
+    /// Send auth/headers/cookies to results on this domain (repeatable;
+    /// --site domains are included automatically)
+    #[arg(long, value_name = "DOMAIN", value_parser = parse_domain)]
+    pub auth_host: Vec<String>,
+
+    #[command(flatten)]
+    pub auth: RequestAuthArgs,
+}
+
+/// Result age limit for `web-search --freshness`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Freshness {
+    Day,
+    Week,
+    Month,
+    Year,
 }
 
 #[derive(Debug, Args)]
@@ -142,3 +239,53 @@ pub struct DocsSectionArgs {
     /// Section heading to extract
     pub heading: String,
 }
+
+#[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct ManifestArgs {
+    #[command(subcommand)]
+    pub cmd: Option<ManifestCommand>,
+
+    /// Directory to hash
+    #[arg(default_value = ".")]
+    pub dir: String,
+
+    /// Hash algorithm
+    #[arg(long, value_enum, default_value_t = HashAlgo::Sha256)]
+    pub algo: HashAlgo,
+
+    /// Write manifest to file instead of stdout
+    #[arg(long, short = 'o')]
+    pub out: Option<String>,
+
+    /// Include hidden files
+    #[arg(long)]
+    pub hidden: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ManifestCommand {
+    /// Report files modified, missing or added since a manifest was written
+    Verify(ManifestVerifyArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ManifestVerifyArgs {
+    /// Manifest file to check against
+    #[arg(default_value = "manifest.json")]
+    pub manifest: String,
+
+    /// Directory to check (default: the directory recorded in the manifest)
+    #[arg(long)]
+    pub dir: Option<String>,
+}
+
+/// Hash algorithm for `utils manifest`
+#[derive(
+    clap::ValueEnum, serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgo {
+    Sha256,
+    Sha512,
+}
@@ -39,7 +39,7 @@ pub fn search_index(index: &DocsIndex, query: &str) -> Vec<SearchResult> {
     }
 
     // Sort by score (descending)
-    results.sort_by(|a, b| b.score.cmp(&a.score));
+    results.sort_by_key(|r| std::cmp::Reverse(r.score));
 
     results
 }
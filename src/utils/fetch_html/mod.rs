@@ -2,15 +2,18 @@ use anyhow::{Context, Result};
 use regex::Regex;
 use std::fs;
 
-use super::cli::FetchHtmlArgs;
+use super::cli::{FetchHtmlArgs, RequestAuthArgs};
+use super::request_auth::get_with_auth;
+use crate::util::timings;
 
 #[cfg(test)]
 mod tests;
 
 /// Handle the `hu utils fetch-html` command
 pub async fn run(args: FetchHtmlArgs) -> Result<()> {
-    let html = fetch_url(&args.url).await?;
+    let html = fetch_url(&args.url, &args.auth).await?;
 
+    let parse_span = timings::span("parse: html");
     let output = if args.raw {
         html_to_markdown(&html)
     } else if args.links {
@@ -26,6 +29,7 @@ pub async fn run(args: FetchHtmlArgs) -> Result<()> {
         // Default: content extraction
         extract_content(&html, None)
     };
+    drop(parse_span);
 
     if let Some(path) = args.output {
         fs::write(&path, &output).with_context(|| format!("Failed to write to {}", path))?;
@@ -37,17 +41,15 @@ pub async fn run(args: FetchHtmlArgs) -> Result<()> {
     Ok(())
 }
 
-/// Fetch URL content
-async fn fetch_url(url: &str) -> Result<String> {
+/// Fetch URL content, applying any auth/headers/cookies
+async fn fetch_url(url: &str, auth: &RequestAuthArgs) -> Result<String> {
+    let _span = timings::span("api: fetch");
     let client = reqwest::Client::builder()
         .user_agent("hu-cli/0.1")
+        .redirect(reqwest::redirect::Policy::none())
         .build()?;
 
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .with_context(|| format!("Failed to fetch {}", url))?;
+    let response = get_with_auth(&client, url, auth).await?;
 
     response
         .text()
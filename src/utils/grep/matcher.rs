@@ -0,0 +1,171 @@
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+
+use crate::utils::cli::GrepArgs;
+
+/// Line matcher combining several patterns with OR (default) or AND
+/// semantics, plus exclusion patterns that veto a line.
+#[derive(Debug)]
+pub struct Matcher {
+    patterns: Vec<Regex>,
+    require_all: bool,
+    exclude: Vec<Regex>,
+}
+
+impl Matcher {
+    /// Build from the positional pattern, `-e` patterns, `--all` and `--not`
+    pub fn from_args(args: &GrepArgs) -> Result<Self> {
+        let sources: Vec<&str> = if args.patterns.is_empty() {
+            args.pattern.as_deref().into_iter().collect()
+        } else {
+            args.patterns.iter().map(String::as_str).collect()
+        };
+        if sources.is_empty() {
+            bail!("No pattern given (use PATTERN or -e)");
+        }
+        if !args.patterns.is_empty() && args.pattern.is_some() && args.path != "." {
+            bail!("With -e, pass at most one path");
+        }
+
+        Ok(Self {
+            patterns: compile(&sources, args.ignore_case)?,
+            require_all: args.all,
+            exclude: compile(
+                &args.not.iter().map(String::as_str).collect::<Vec<_>>(),
+                args.ignore_case,
+            )?,
+        })
+    }
+
+    /// Number of pattern hits on a line, or 0 if the line doesn't qualify
+    pub fn count(&self, line: &str) -> usize {
+        if self.exclude.iter().any(|re| re.is_match(line)) {
+            return 0;
+        }
+
+        let counts = self.patterns.iter().map(|re| re.find_iter(line).count());
+        if self.require_all {
+            counts
+                .map(|c| (c > 0).then_some(c))
+                .sum::<Option<usize>>()
+                .unwrap_or(0)
+        } else {
+            counts.sum()
+        }
+    }
+}
+
+fn compile(patterns: &[&str], ignore_case: bool) -> Result<Vec<Regex>> {
+    patterns
+        .iter()
+        .map(|p| {
+            let source = if ignore_case {
+                format!("(?i){}", p)
+            } else {
+                p.to_string()
+            };
+            Regex::new(&source).with_context(|| format!("Invalid regex pattern: {}", p))
+        })
+        .collect()
+}
+
+/// With `-e`, a lone positional argument is the path to search (as in grep)
+pub fn resolve_path(args: &GrepArgs) -> &str {
+    match &args.pattern {
+        Some(path) if !args.patterns.is_empty() && args.path == "." => path,
+        _ => &args.path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(pattern: Option<&str>, patterns: &[&str], all: bool, not: &[&str]) -> GrepArgs {
+        GrepArgs {
+            pattern: pattern.map(str::to_string),
+            path: ".".to_string(),
+            patterns: patterns.iter().map(|s| s.to_string()).collect(),
+            all,
+            not: not.iter().map(|s| s.to_string()).collect(),
+            refs: false,
+            unique: false,
+            unique_by: None,
+            ranked: false,
+            limit: None,
+            signature: false,
+            glob: None,
+            ignore_case: false,
+            hidden: false,
+            stats: false,
+            json: false,
+            redact: false,
+        }
+    }
+
+    #[test]
+    fn single_positional_pattern() {
+        let m = Matcher::from_args(&args(Some("fn"), &[], false, &[])).unwrap();
+        assert_eq!(m.count("fn a() { fn b() }"), 2);
+        assert_eq!(m.count("struct X"), 0);
+    }
+
+    #[test]
+    fn or_semantics_by_default() {
+        let m = Matcher::from_args(&args(None, &["foo", "bar"], false, &[])).unwrap();
+        assert_eq!(m.count("foo"), 1);
+        assert_eq!(m.count("bar bar"), 2);
+        assert_eq!(m.count("foo bar"), 2);
+        assert_eq!(m.count("baz"), 0);
+    }
+
+    #[test]
+    fn all_requires_every_pattern() {
+        let m = Matcher::from_args(&args(None, &["foo", "bar"], true, &[])).unwrap();
+        assert_eq!(m.count("foo only"), 0);
+        assert_eq!(m.count("foo and bar"), 2);
+    }
+
+    #[test]
+    fn not_excludes_lines() {
+        let m = Matcher::from_args(&args(Some("unwrap"), &[], false, &["test"])).unwrap();
+        assert_eq!(m.count("x.unwrap()"), 1);
+        assert_eq!(m.count("fn test_x() { x.unwrap() }"), 0);
+    }
+
+    #[test]
+    fn ignore_case_applies_to_all_patterns() {
+        let mut a = args(None, &["foo"], false, &["skip"]);
+        a.ignore_case = true;
+        let m = Matcher::from_args(&a).unwrap();
+        assert_eq!(m.count("FOO"), 1);
+        assert_eq!(m.count("FOO SKIP"), 0);
+    }
+
+    #[test]
+    fn missing_pattern_errors() {
+        assert!(Matcher::from_args(&args(None, &[], false, &[])).is_err());
+    }
+
+    #[test]
+    fn invalid_pattern_errors() {
+        assert!(Matcher::from_args(&args(None, &["ok", "[bad"], false, &[])).is_err());
+        assert!(Matcher::from_args(&args(Some("ok"), &[], false, &["(bad"])).is_err());
+    }
+
+    #[test]
+    fn extra_positional_with_e_errors() {
+        let mut a = args(Some("src"), &["foo"], false, &[]);
+        a.path = "lib".to_string();
+        assert!(Matcher::from_args(&a).is_err());
+    }
+
+    #[test]
+    fn positional_becomes_path_with_e() {
+        assert_eq!(
+            resolve_path(&args(Some("src"), &["foo"], false, &[])),
+            "src"
+        );
+        assert_eq!(resolve_path(&args(Some("foo"), &[], false, &[])), ".");
+    }
+}
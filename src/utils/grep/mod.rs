@@ -1,17 +1,23 @@
 use anyhow::{Context, Result};
 use regex::Regex;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::time::Instant;
 
-use super::cli::GrepArgs;
+use super::cli::{GrepArgs, UniqueBy};
 use super::signature::extract_signature;
+use crate::util::walk::{is_binary_extension, is_ignored_dir};
+use crate::util::{redact, timings};
+use matcher::Matcher;
 
+mod matcher;
 #[cfg(test)]
 mod tests;
 
 /// A single grep match
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GrepMatch {
     pub file: String,
     pub line_num: usize,
@@ -19,40 +25,98 @@ pub struct GrepMatch {
     pub match_count: usize,
 }
 
+/// Number of files listed in the stats "top files" section
+const TOP_FILES_LIMIT: usize = 10;
+
+/// Summary statistics over all raw matches of a search
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GrepStats {
+    pub files_scanned: usize,
+    pub files_matched: usize,
+    pub total_matches: usize,
+    pub top_files: Vec<FileMatchCount>,
+    pub elapsed_ms: u64,
+}
+
+/// Match count for a single file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileMatchCount {
+    pub file: String,
+    pub matches: usize,
+}
+
+/// JSON envelope for `--json` output
+#[derive(Debug, Serialize)]
+struct GrepOutput<'a> {
+    matches: &'a [GrepMatch],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<&'a GrepStats>,
+}
+
+/// Raw matches plus scan bookkeeping, filled while walking
+#[derive(Debug, Default)]
+struct Scan {
+    matches: Vec<GrepMatch>,
+    files_scanned: usize,
+    /// Mask secrets in matched lines (see `redact::redact_lines`)
+    redact: bool,
+}
+
 /// Handle the `hu utils grep` command
-pub fn run(args: GrepArgs) -> Result<()> {
-    let matches = search_files(&args)?;
+pub fn run(mut args: GrepArgs) -> Result<()> {
+    args.redact = args.redact || redact::enabled_by_default();
+    let (matches, stats) = search_files_with_stats(&args)?;
+    let _span = timings::span("render");
+    let stats = args.stats.then_some(&stats);
+
+    if args.json {
+        let envelope = GrepOutput {
+            matches: &matches,
+            stats,
+        };
+        println!("{}", serde_json::to_string_pretty(&envelope)?);
+        return Ok(());
+    }
 
     if matches.is_empty() {
         eprintln!("No matches found.");
-        return Ok(());
+    } else {
+        println!("{}", format_matches(&matches, &args));
     }
 
-    let output = format_matches(&matches, &args);
-    println!("{}", output);
+    if let Some(stats) = stats {
+        println!("\n{}", format_stats(stats));
+    }
 
     Ok(())
 }
 
-/// Search files for pattern
-pub fn search_files(args: &GrepArgs) -> Result<Vec<GrepMatch>> {
-    let re = if args.ignore_case {
-        Regex::new(&format!("(?i){}", &args.pattern))
-    } else {
-        Regex::new(&args.pattern)
-    }
-    .with_context(|| format!("Invalid regex pattern: {}", args.pattern))?;
+/// Search files for pattern, also returning stats over the raw matches
+pub fn search_files_with_stats(args: &GrepArgs) -> Result<(Vec<GrepMatch>, GrepStats)> {
+    let started = Instant::now();
+    let matcher = Matcher::from_args(args)?;
+    let path = matcher::resolve_path(args);
 
     let glob_pattern = args.glob.as_deref();
-    let mut matches = Vec::new();
+    let mut scan = Scan {
+        redact: args.redact,
+        ..Scan::default()
+    };
+
+    {
+        // Includes the per-file "search: file" spans recorded inside it
+        let _span = timings::span("walk: grep");
+        collect_matches(path, &matcher, glob_pattern, args.hidden, &mut scan)?;
+    }
 
-    collect_matches(&args.path, &re, glob_pattern, args.hidden, &mut matches)?;
+    let mut stats = compute_stats(&scan.matches, scan.files_scanned);
+    stats.elapsed_ms = started.elapsed().as_millis() as u64;
 
     // Apply post-processing
-    let mut matches = if args.unique {
-        dedupe_matches(matches)
-    } else {
-        matches
+    let unique_by = args.unique_by.or(args.unique.then_some(UniqueBy::Content));
+    let mut matches = match unique_by {
+        Some(key) => dedupe_matches(scan.matches, key),
+        None => scan.matches,
     };
 
     if args.ranked {
@@ -63,22 +127,22 @@ pub fn search_files(args: &GrepArgs) -> Result<Vec<GrepMatch>> {
         matches.truncate(limit);
     }
 
-    Ok(matches)
+    Ok((matches, stats))
 }
 
 /// Recursively collect matches from files
 fn collect_matches(
     path: &str,
-    re: &Regex,
+    matcher: &Matcher,
     glob_pattern: Option<&str>,
     include_hidden: bool,
-    matches: &mut Vec<GrepMatch>,
+    scan: &mut Scan,
 ) -> Result<()> {
     let path = Path::new(path);
 
     if path.is_file() {
         if should_search_file(path, glob_pattern) {
-            search_file(path, re, matches)?;
+            search_file(path, matcher, scan)?;
         }
         return Ok(());
     }
@@ -110,40 +174,19 @@ fn collect_matches(
         if entry_path.is_dir() {
             collect_matches(
                 entry_path.to_str().unwrap_or(""),
-                re,
+                matcher,
                 glob_pattern,
                 include_hidden,
-                matches,
+                scan,
             )?;
         } else if should_search_file(&entry_path, glob_pattern) {
-            search_file(&entry_path, re, matches)?;
+            search_file(&entry_path, matcher, scan)?;
         }
     }
 
     Ok(())
 }
 
-/// Check if a directory should be ignored
-fn is_ignored_dir(name: &str) -> bool {
-    matches!(
-        name,
-        "node_modules"
-            | "target"
-            | ".git"
-            | ".svn"
-            | ".hg"
-            | "__pycache__"
-            | ".mypy_cache"
-            | ".pytest_cache"
-            | "venv"
-            | ".venv"
-            | "dist"
-            | "build"
-            | ".next"
-            | ".nuxt"
-    )
-}
-
 /// Check if a file matches the glob pattern
 fn should_search_file(path: &Path, glob_pattern: Option<&str>) -> bool {
     // Skip binary files
@@ -162,55 +205,6 @@ fn should_search_file(path: &Path, glob_pattern: Option<&str>) -> bool {
     glob_matches(file_name, pattern)
 }
 
-/// Check if extension indicates binary file
-fn is_binary_extension(ext: &str) -> bool {
-    matches!(
-        ext.to_lowercase().as_str(),
-        "png"
-            | "jpg"
-            | "jpeg"
-            | "gif"
-            | "ico"
-            | "webp"
-            | "bmp"
-            | "svg"
-            | "pdf"
-            | "zip"
-            | "tar"
-            | "gz"
-            | "bz2"
-            | "xz"
-            | "7z"
-            | "rar"
-            | "exe"
-            | "dll"
-            | "so"
-            | "dylib"
-            | "a"
-            | "o"
-            | "obj"
-            | "wasm"
-            | "class"
-            | "jar"
-            | "pyc"
-            | "pyo"
-            | "mp3"
-            | "mp4"
-            | "avi"
-            | "mkv"
-            | "mov"
-            | "wav"
-            | "flac"
-            | "ttf"
-            | "otf"
-            | "woff"
-            | "woff2"
-            | "eot"
-            | "sqlite"
-            | "db"
-    )
-}
-
 /// Simple glob matching (supports * and ?)
 pub fn glob_matches(name: &str, pattern: &str) -> bool {
     let pattern = pattern.trim_start_matches("**/");
@@ -234,21 +228,26 @@ pub fn glob_matches(name: &str, pattern: &str) -> bool {
 }
 
 /// Search a single file for matches
-fn search_file(path: &Path, re: &Regex, matches: &mut Vec<GrepMatch>) -> Result<()> {
+fn search_file(path: &Path, matcher: &Matcher, scan: &mut Scan) -> Result<()> {
+    let _span = timings::span("search: file");
     let content = match fs::read_to_string(path) {
         Ok(c) => c,
         Err(_) => return Ok(()), // Skip unreadable files
     };
 
+    scan.files_scanned += 1;
     let file_str = path.to_str().unwrap_or("");
+    // Whole-file pass so multi-line secrets (key blocks) are masked too
+    let redacted = scan.redact.then(|| redact::redact_lines(&content));
 
     for (line_num, line) in content.lines().enumerate() {
-        let match_count = re.find_iter(line).count();
+        let match_count = matcher.count(line);
         if match_count > 0 {
-            matches.push(GrepMatch {
+            let shown = redacted.as_ref().map_or(line, |r| r[line_num].as_str());
+            scan.matches.push(GrepMatch {
                 file: file_str.to_string(),
                 line_num: line_num + 1,
-                content: line.to_string(),
+                content: shown.to_string(),
                 match_count,
             });
         }
@@ -257,20 +256,80 @@ fn search_file(path: &Path, re: &Regex, matches: &mut Vec<GrepMatch>) -> Result<
     Ok(())
 }
 
-/// Deduplicate similar matches
-fn dedupe_matches(matches: Vec<GrepMatch>) -> Vec<GrepMatch> {
-    let mut seen: HashMap<String, GrepMatch> = HashMap::new();
+/// Compute per-file totals and the busiest files (elapsed is filled by caller)
+fn compute_stats(matches: &[GrepMatch], files_scanned: usize) -> GrepStats {
+    let mut per_file: HashMap<&str, usize> = HashMap::new();
+    for m in matches {
+        *per_file.entry(m.file.as_str()).or_default() += m.match_count;
+    }
+
+    let mut top_files: Vec<FileMatchCount> = per_file
+        .iter()
+        .map(|(file, count)| FileMatchCount {
+            file: (*file).to_string(),
+            matches: *count,
+        })
+        .collect();
+    top_files.sort_by(|a, b| b.matches.cmp(&a.matches).then_with(|| a.file.cmp(&b.file)));
+    top_files.truncate(TOP_FILES_LIMIT);
+
+    GrepStats {
+        files_scanned,
+        files_matched: per_file.len(),
+        total_matches: per_file.values().sum(),
+        top_files,
+        elapsed_ms: 0,
+    }
+}
+
+/// Format stats summary for text output
+pub fn format_stats(stats: &GrepStats) -> String {
+    let mut lines = vec![
+        format!("Files scanned: {}", stats.files_scanned),
+        format!("Files matched: {}", stats.files_matched),
+        format!("Total matches: {}", stats.total_matches),
+        format!("Elapsed: {}ms", stats.elapsed_ms),
+    ];
+
+    if !stats.top_files.is_empty() {
+        lines.push("Top files:".to_string());
+        let width = stats.top_files[0].matches.to_string().len();
+        for entry in &stats.top_files {
+            lines.push(format!(
+                "  {:>width$}  {}",
+                entry.matches,
+                entry.file,
+                width = width
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Deduplicate matches by `key`, keeping the first occurrence and summing counts
+fn dedupe_matches(matches: Vec<GrepMatch>, key: UniqueBy) -> Vec<GrepMatch> {
+    let mut kept: Vec<GrepMatch> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
 
     for m in matches {
         // Normalize content for comparison (trim, collapse whitespace)
         let normalized = m.content.split_whitespace().collect::<Vec<_>>().join(" ");
-
-        seen.entry(normalized)
-            .and_modify(|existing| existing.match_count += m.match_count)
-            .or_insert(m);
+        let dedupe_key = match key {
+            UniqueBy::File => format!("{}\0{}", m.file, normalized),
+            UniqueBy::Content => normalized,
+        };
+
+        match index.get(&dedupe_key) {
+            Some(&i) => kept[i].match_count += m.match_count,
+            None => {
+                index.insert(dedupe_key, kept.len());
+                kept.push(m);
+            }
+        }
     }
 
-    seen.into_values().collect()
+    kept
 }
 
 /// Rank matches by relevance (match density)
@@ -0,0 +1,257 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use super::cli::{HashAlgo, ManifestArgs, ManifestCommand, ManifestVerifyArgs};
+use crate::util::walk::walk_all_files;
+
+#[cfg(test)]
+mod tests;
+
+/// Read buffer size when hashing
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Checksums for every file under a directory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub algo: HashAlgo,
+    /// Directory the manifest was built from (as given on the command line)
+    pub root: String,
+    /// Whether hidden files were included
+    #[serde(default)]
+    pub hidden: bool,
+    pub files: Vec<ManifestEntry>,
+}
+
+/// A hashed file, with its path relative to the manifest root
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub size: u64,
+    pub hash: String,
+}
+
+/// Differences between a manifest and the directory on disk
+#[derive(Debug, Default, PartialEq)]
+pub struct ManifestDiff {
+    pub modified: Vec<String>,
+    pub missing: Vec<String>,
+    pub added: Vec<String>,
+    /// Files whose hash matched
+    pub unchanged: usize,
+}
+
+impl ManifestDiff {
+    pub fn change_count(&self) -> usize {
+        self.modified.len() + self.missing.len() + self.added.len()
+    }
+}
+
+/// Hash all files under `root` (skipping ignored dirs and `exclude`)
+pub fn build_manifest(
+    root: &Path,
+    algo: HashAlgo,
+    hidden: bool,
+    exclude: Option<&Path>,
+) -> Result<Manifest> {
+    if !root.is_dir() {
+        bail!("Not a directory: {}", root.display());
+    }
+
+    let files = list_files(root, hidden, exclude)?;
+    Ok(Manifest {
+        algo,
+        root: root.display().to_string(),
+        hidden,
+        files: hash_files(root, &files, algo)?,
+    })
+}
+
+/// Compare `manifest` against the current contents of `root`
+pub fn verify_manifest(
+    manifest: &Manifest,
+    root: &Path,
+    exclude: Option<&Path>,
+) -> Result<ManifestDiff> {
+    let files = list_files(root, manifest.hidden, exclude)?;
+    let current: BTreeMap<String, ManifestEntry> = hash_files(root, &files, manifest.algo)?
+        .into_iter()
+        .map(|e| (e.path.clone(), e))
+        .collect();
+
+    let mut diff = ManifestDiff::default();
+    for entry in &manifest.files {
+        match current.get(&entry.path) {
+            None => diff.missing.push(entry.path.clone()),
+            Some(now) if now.hash != entry.hash => diff.modified.push(entry.path.clone()),
+            Some(_) => diff.unchanged += 1,
+        }
+    }
+    let recorded: HashSet<&str> = manifest.files.iter().map(|e| e.path.as_str()).collect();
+    diff.added = current
+        .into_keys()
+        .filter(|path| !recorded.contains(&path.as_str()))
+        .collect();
+
+    Ok(diff)
+}
+
+/// Human-readable verification report (`M`/`D`/`A` lines and a summary)
+pub fn format_diff(diff: &ManifestDiff) -> String {
+    if diff.change_count() == 0 {
+        return format!("OK: {} files match the manifest", diff.unchanged);
+    }
+
+    let mut lines = Vec::new();
+    lines.extend(diff.modified.iter().map(|p| format!("M {}", p)));
+    lines.extend(diff.missing.iter().map(|p| format!("D {}", p)));
+    lines.extend(diff.added.iter().map(|p| format!("A {}", p)));
+    lines.push(format!(
+        "{} change(s): {} modified, {} missing, {} added",
+        diff.change_count(),
+        diff.modified.len(),
+        diff.missing.len(),
+        diff.added.len()
+    ));
+    lines.join("\n")
+}
+
+fn list_files(root: &Path, hidden: bool, exclude: Option<&Path>) -> Result<Vec<PathBuf>> {
+    let files = walk_all_files(root, hidden)?;
+    let Some(exclude) = exclude.map(canonical_exclude).transpose()?.flatten() else {
+        return Ok(files);
+    };
+
+    let canonical_root =
+        fs::canonicalize(root).with_context(|| format!("Failed to resolve {}", root.display()))?;
+    Ok(files
+        .into_iter()
+        .filter(|f| {
+            let rel = f.strip_prefix(root).unwrap_or(f);
+            canonical_root.join(rel) != exclude
+        })
+        .collect())
+}
+
+/// Canonical form of the excluded manifest path; `None` if it doesn't exist
+/// yet (a manifest being written for the first time can't be walked)
+fn canonical_exclude(path: &Path) -> Result<Option<PathBuf>> {
+    match fs::canonicalize(path) {
+        Ok(path) => Ok(Some(path)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Failed to resolve {}", path.display())),
+    }
+}
+
+/// Hash files across all available cores, preserving input order
+fn hash_files(root: &Path, files: &[PathBuf], algo: HashAlgo) -> Result<Vec<ManifestEntry>> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_len = files.len().div_ceil(threads).max(1);
+
+    let chunks: Vec<Result<Vec<ManifestEntry>>> = thread::scope(|scope| {
+        let handles: Vec<_> = files
+            .chunks(chunk_len)
+            .map(|chunk| {
+                scope.spawn(move || chunk.iter().map(|f| hash_entry(root, f, algo)).collect())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("invariant: hashing threads do not panic"))
+            .collect()
+    });
+
+    let mut entries = Vec::with_capacity(files.len());
+    for chunk in chunks {
+        entries.extend(chunk?);
+    }
+    Ok(entries)
+}
+
+fn hash_entry(root: &Path, path: &Path, algo: HashAlgo) -> Result<ManifestEntry> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let size = file
+        .metadata()
+        .with_context(|| format!("Failed to read metadata for {}", path.display()))?
+        .len();
+    let reader = BufReader::with_capacity(CHUNK_SIZE, file);
+    let hash = match algo {
+        HashAlgo::Sha256 => digest::<Sha256>(reader),
+        HashAlgo::Sha512 => digest::<Sha512>(reader),
+    }
+    .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let rel = path.strip_prefix(root).unwrap_or(path);
+    let rel: Vec<_> = rel
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect();
+    Ok(ManifestEntry {
+        path: rel.join("/"),
+        size,
+        hash,
+    })
+}
+
+fn digest<D: Digest>(mut reader: impl Read) -> std::io::Result<String> {
+    let mut hasher = D::new();
+    let mut buf = vec![0; CHUNK_SIZE];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Handle the `hu utils manifest` command
+#[cfg(not(tarpaulin_include))]
+pub fn run(args: ManifestArgs) -> Result<()> {
+    if let Some(ManifestCommand::Verify(verify)) = args.cmd {
+        return run_verify(verify);
+    }
+
+    let manifest = build_manifest(
+        Path::new(&args.dir),
+        args.algo,
+        args.hidden,
+        args.out.as_deref().map(Path::new),
+    )?;
+    let json = serde_json::to_string_pretty(&manifest)?;
+
+    match args.out {
+        Some(path) => {
+            fs::write(&path, json + "\n").with_context(|| format!("Failed to write {}", path))?;
+            eprintln!(
+                "Wrote manifest for {} files to {}",
+                manifest.files.len(),
+                path
+            );
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+#[cfg(not(tarpaulin_include))]
+fn run_verify(args: ManifestVerifyArgs) -> Result<()> {
+    let content = fs::read_to_string(&args.manifest)
+        .with_context(|| format!("Failed to read {}", args.manifest))?;
+    let manifest: Manifest = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid manifest: {}", args.manifest))?;
+    let root = args.dir.as_deref().unwrap_or(&manifest.root);
+
+    let diff = verify_manifest(&manifest, Path::new(root), Some(Path::new(&args.manifest)))?;
+    println!("{}", format_diff(&diff));
+    if diff.change_count() > 0 {
+        bail!("Directory does not match {}", args.manifest);
+    }
+    Ok(())
+}
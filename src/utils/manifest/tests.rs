@@ -0,0 +1,119 @@
+use super::*;
+
+fn fixture() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("bin")).unwrap();
+    fs::create_dir_all(root.join("node_modules/x")).unwrap();
+    fs::write(root.join("app.js"), "console.log(1)").unwrap();
+    fs::write(root.join("bin/logo.png"), [0u8, 1, 2, 255]).unwrap();
+    fs::write(root.join(".env"), "SECRET=1").unwrap();
+    fs::write(root.join("node_modules/x/index.js"), "").unwrap();
+    dir
+}
+
+fn paths(manifest: &Manifest) -> Vec<&str> {
+    manifest.files.iter().map(|e| e.path.as_str()).collect()
+}
+
+#[test]
+fn build_hashes_files_including_binary() {
+    let dir = fixture();
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha256, false, None).unwrap();
+    assert_eq!(paths(&manifest), vec!["app.js", "bin/logo.png"]);
+    assert_eq!(manifest.files[0].size, 14);
+    assert_eq!(manifest.files[0].hash.len(), 64);
+}
+
+#[test]
+fn build_with_hidden_and_sha512() {
+    let dir = fixture();
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha512, true, None).unwrap();
+    assert_eq!(paths(&manifest), vec![".env", "app.js", "bin/logo.png"]);
+    assert_eq!(manifest.files[0].hash.len(), 128);
+}
+
+#[test]
+fn known_sha256_digest() {
+    let hash = digest::<Sha256>("abc".as_bytes()).unwrap();
+    assert_eq!(
+        hash,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn build_excludes_output_file() {
+    let dir = fixture();
+    let out = dir.path().join("manifest.json");
+    fs::write(&out, "{}").unwrap();
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha256, false, Some(&out)).unwrap();
+    assert!(!paths(&manifest).contains(&"manifest.json"));
+}
+
+#[test]
+fn build_excludes_output_given_via_dotted_path() {
+    let dir = fixture();
+    let out = dir.path().join("bin/../manifest.json");
+    fs::write(&out, "{}").unwrap();
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha256, false, Some(&out)).unwrap();
+    assert_eq!(paths(&manifest), vec!["app.js", "bin/logo.png"]);
+}
+
+#[test]
+fn build_with_missing_exclude_keeps_all_files() {
+    let dir = fixture();
+    let out = dir.path().join("not-yet-written.json");
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha256, false, Some(&out)).unwrap();
+    assert_eq!(paths(&manifest), vec!["app.js", "bin/logo.png"]);
+}
+
+#[test]
+fn build_rejects_non_directory() {
+    let dir = fixture();
+    let file = dir.path().join("app.js");
+    assert!(build_manifest(&file, HashAlgo::Sha256, false, None).is_err());
+}
+
+#[test]
+fn manifest_json_round_trip() {
+    let dir = fixture();
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha512, false, None).unwrap();
+    let json = serde_json::to_string(&manifest).unwrap();
+    assert!(json.contains("\"algo\":\"sha512\""));
+    assert_eq!(serde_json::from_str::<Manifest>(&json).unwrap(), manifest);
+}
+
+#[test]
+fn verify_clean_directory() {
+    let dir = fixture();
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha256, false, None).unwrap();
+    let diff = verify_manifest(&manifest, dir.path(), None).unwrap();
+    assert_eq!(diff.change_count(), 0);
+    assert_eq!(diff.unchanged, 2);
+    assert_eq!(format_diff(&diff), "OK: 2 files match the manifest");
+}
+
+#[test]
+fn verify_reports_changes() {
+    let dir = fixture();
+    let manifest = build_manifest(dir.path(), HashAlgo::Sha256, false, None).unwrap();
+    fs::write(dir.path().join("app.js"), "console.log(2)").unwrap();
+    fs::remove_file(dir.path().join("bin/logo.png")).unwrap();
+    fs::write(dir.path().join("new.txt"), "").unwrap();
+
+    let diff = verify_manifest(&manifest, dir.path(), None).unwrap();
+    assert_eq!(
+        diff,
+        ManifestDiff {
+            modified: vec!["app.js".to_string()],
+            missing: vec!["bin/logo.png".to_string()],
+            added: vec!["new.txt".to_string()],
+            unchanged: 0,
+        }
+    );
+    assert_eq!(
+        format_diff(&diff),
+        "M app.js\nD bin/logo.png\nA new.txt\n3 change(s): 1 modified, 1 missing, 1 added"
+    );
+}
@@ -4,6 +4,8 @@ mod docs_search;
 mod docs_section;
 mod fetch_html;
 mod grep;
+mod manifest;
+mod request_auth;
 mod signature;
 mod web_search;
 
@@ -11,6 +13,8 @@ pub use cli::UtilsCommand;
 
 use anyhow::Result;
 
+use crate::util::timings;
+
 /// Run a utils subcommand
 #[cfg(not(tarpaulin_include))]
 pub async fn run_command(cmd: UtilsCommand) -> Result<()> {
@@ -21,6 +25,7 @@ pub async fn run_command(cmd: UtilsCommand) -> Result<()> {
         UtilsCommand::DocsIndex(args) => run_docs_index(args),
         UtilsCommand::DocsSearch(args) => run_docs_search(args),
         UtilsCommand::DocsSection(args) => run_docs_section(args),
+        UtilsCommand::Manifest(args) => manifest::run(args),
     }
 }
 
@@ -28,8 +33,12 @@ use cli::{DocsIndexArgs, DocsSearchArgs, DocsSectionArgs};
 
 #[cfg(not(tarpaulin_include))]
 fn run_docs_index(args: DocsIndexArgs) -> Result<()> {
-    let index = docs_index::build_index(&args.path)?;
+    let index = {
+        let _span = timings::span("walk: docs index");
+        docs_index::build_index(&args.path)?
+    };
 
+    let _span = timings::span("render");
     if let Some(output) = args.output {
         docs_index::save_index(&index, &output)?;
         println!("Index saved to {}", output);
@@ -56,8 +65,15 @@ fn run_docs_index(args: DocsIndexArgs) -> Result<()> {
 
 #[cfg(not(tarpaulin_include))]
 fn run_docs_search(args: DocsSearchArgs) -> Result<()> {
-    let index = docs_index::load_index(&args.index)?;
-    let results = docs_search::search_index(&index, &args.query);
+    let index = {
+        let _span = timings::span("parse: docs index");
+        docs_index::load_index(&args.index)?
+    };
+    let results = {
+        let _span = timings::span("search: docs");
+        docs_search::search_index(&index, &args.query)
+    };
+    let _span = timings::span("render");
     let output = docs_search::format_results(&results, args.limit);
     println!("{}", output);
     Ok(())
@@ -65,7 +81,10 @@ fn run_docs_search(args: DocsSearchArgs) -> Result<()> {
 
 #[cfg(not(tarpaulin_include))]
 fn run_docs_section(args: DocsSectionArgs) -> Result<()> {
-    let content = docs_section::extract_section_from_file(&args.file, &args.heading)?;
+    let content = {
+        let _span = timings::span("parse: docs section");
+        docs_section::extract_section_from_file(&args.file, &args.heading)?
+    };
     println!("{}", content);
     Ok(())
 }
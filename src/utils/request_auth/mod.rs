@@ -0,0 +1,231 @@
+//! Auth headers, extra headers and cookie jars for page fetching.
+//!
+//! Shared by `fetch-html` and `web-search`. Secrets never appear in
+//! Debug output or error messages; see [`redact`].
+
+use anyhow::{anyhow, bail, Context, Result};
+use reqwest::header::LOCATION;
+use reqwest::{Client, Request, RequestBuilder, Response, Url};
+use std::fmt;
+use std::fs;
+use std::time::SystemTime;
+
+use super::cli::RequestAuthArgs;
+
+#[cfg(test)]
+mod tests;
+
+/// Placeholder shown instead of secret values
+const REDACTED: &str = "***";
+
+/// Redirects followed by [`get_with_auth`] before giving up
+const MAX_REDIRECTS: usize = 10;
+
+/// Header names whose values are treated as secrets
+const SENSITIVE_HEADER_HINTS: &[&str] = &[
+    "auth", "token", "key", "secret", "cookie", "session", "password",
+];
+
+impl fmt::Debug for RequestAuthArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let headers: Vec<String> = self.headers.iter().map(|h| redact(h, self)).collect();
+        f.debug_struct("RequestAuthArgs")
+            .field("headers", &headers)
+            .field("basic", &self.basic.as_deref().map(|b| redact(b, self)))
+            .field("bearer", &self.bearer.as_ref().map(|_| REDACTED))
+            .field("cookie_jar", &self.cookie_jar)
+            .finish()
+    }
+}
+
+impl RequestAuthArgs {
+    /// Whether any header, credential or cookie jar was given
+    pub fn has_credentials(&self) -> bool {
+        !self.headers.is_empty()
+            || self.basic.is_some()
+            || self.bearer.is_some()
+            || self.cookie_jar.is_some()
+    }
+}
+
+/// Apply headers, basic/bearer auth and jar cookies to a request
+pub fn apply_auth(
+    mut builder: RequestBuilder,
+    auth: &RequestAuthArgs,
+    url: &str,
+) -> Result<RequestBuilder> {
+    for raw in &auth.headers {
+        let (name, value) = parse_header(raw)?;
+        builder = builder.header(name, value);
+    }
+
+    if let Some(basic) = &auth.basic {
+        let (user, pass) = basic
+            .split_once(':')
+            .context("--basic must be in USER:PASS form")?;
+        builder = builder.basic_auth(user, Some(pass));
+    }
+
+    if let Some(token) = &auth.bearer {
+        builder = builder.bearer_auth(token);
+    }
+
+    if let Some(jar) = &auth.cookie_jar {
+        let content = fs::read_to_string(jar)
+            .with_context(|| format!("Failed to read cookie jar {}", jar))?;
+        let cookies = cookies_for_url(&content, url, current_timestamp())?;
+        if !cookies.is_empty() {
+            builder = builder.header("Cookie", cookies.join("; "));
+        }
+    }
+
+    Ok(builder)
+}
+
+/// GET `url`, following redirects by hand so auth is only sent while the
+/// request stays on the original host. reqwest's own redirect handling
+/// strips `Authorization`/`Cookie` across hosts but keeps custom headers
+/// such as `X-Api-Key`, so `client` must be built with
+/// `redirect::Policy::none()`.
+pub async fn get_with_auth(client: &Client, url: &str, auth: &RequestAuthArgs) -> Result<Response> {
+    let origin = Url::parse(url).with_context(|| format!("Invalid URL: {}", url))?;
+    let mut current = origin.clone();
+
+    for _ in 0..=MAX_REDIRECTS {
+        let request = hop_request(client, &origin, &current, auth)?;
+        let response = client
+            .execute(request)
+            .await
+            .map_err(|e| anyhow!(redact(&format!("Failed to fetch {}: {}", current, e), auth)))?;
+
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|v| v.to_str().ok());
+        match location {
+            Some(location) if response.status().is_redirection() => {
+                current = current
+                    .join(location)
+                    .with_context(|| format!("Invalid redirect from {}", current))?;
+            }
+            _ => return Ok(response),
+        }
+    }
+
+    bail!("Too many redirects fetching {}", url)
+}
+
+/// Request for one redirect hop; auth is attached only while the hop stays
+/// on the origin's scheme, host and port (so an https -> http downgrade
+/// never resends credentials in plaintext)
+pub fn hop_request(
+    client: &Client,
+    origin: &Url,
+    url: &Url,
+    auth: &RequestAuthArgs,
+) -> Result<Request> {
+    let builder = client.get(url.clone());
+    let same_origin = origin.scheme() == url.scheme()
+        && origin.host_str() == url.host_str()
+        && origin.port_or_known_default() == url.port_or_known_default();
+    let builder = if same_origin {
+        apply_auth(builder, auth, url.as_str())?
+    } else {
+        builder
+    };
+    builder.build().context("Failed to build request")
+}
+
+/// Parse a `Key: Value` header argument
+fn parse_header(raw: &str) -> Result<(String, String)> {
+    match raw.split_once(':') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        // Don't echo the argument back: it may contain a secret
+        _ => bail!("Invalid --header (expected 'Key: Value')"),
+    }
+}
+
+/// Select `name=value` pairs from a Netscape cookie file that apply to `url`
+fn cookies_for_url(content: &str, url: &str, now: u64) -> Result<Vec<String>> {
+    let url = Url::parse(url).with_context(|| format!("Invalid URL: {}", url))?;
+    let host = url.host_str().unwrap_or("");
+    let is_https = url.scheme() == "https";
+
+    let cookies = content
+        .lines()
+        .filter_map(|line| {
+            // curl marks HttpOnly cookies with this prefix; other '#' lines are comments
+            let line = line.strip_prefix("#HttpOnly_").unwrap_or(line);
+            if line.starts_with('#') || line.trim().is_empty() {
+                return None;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [domain, subdomains, path, secure, expires, name, value] = fields[..] else {
+                return None;
+            };
+
+            let domain = domain.trim_start_matches('.');
+            let domain_ok =
+                host == domain || (subdomains == "TRUE" && host.ends_with(&format!(".{}", domain)));
+            let path_ok = path_matches(url.path(), path);
+            let secure_ok = secure != "TRUE" || is_https;
+            let expires: u64 = expires.parse().unwrap_or(0);
+            let fresh = expires == 0 || expires > now;
+
+            (domain_ok && path_ok && secure_ok && fresh).then(|| format!("{}={}", name, value))
+        })
+        .collect();
+
+    Ok(cookies)
+}
+
+/// RFC 6265 path-match: `/docs` covers `/docs` and `/docs/a`, not `/docsevil`
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    match request_path.strip_prefix(cookie_path) {
+        Some(rest) => rest.is_empty() || cookie_path.ends_with('/') || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+/// Replace every secret supplied via `auth` in `text` with `***`
+pub fn redact(text: &str, auth: &RequestAuthArgs) -> String {
+    let mut out = text.to_string();
+    for secret in secrets(auth) {
+        out = out.replace(&secret, REDACTED);
+    }
+    out
+}
+
+/// Secret values: basic password, bearer token and sensitive header values
+fn secrets(auth: &RequestAuthArgs) -> Vec<String> {
+    let mut secrets: Vec<String> = auth
+        .headers
+        .iter()
+        .filter_map(|raw| raw.split_once(':'))
+        .filter(|(name, _)| {
+            let name = name.to_lowercase();
+            SENSITIVE_HEADER_HINTS
+                .iter()
+                .any(|hint| name.contains(hint))
+        })
+        .map(|(_, value)| value.trim().to_string())
+        .collect();
+
+    if let Some((_, pass)) = auth.basic.as_deref().and_then(|b| b.split_once(':')) {
+        secrets.push(pass.to_string());
+    }
+    secrets.extend(auth.bearer.clone());
+
+    secrets.retain(|s| !s.is_empty());
+    secrets
+}
+
+/// Get current Unix timestamp
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
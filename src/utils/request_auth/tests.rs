@@ -0,0 +1,182 @@
+use super::*;
+
+const JAR: &str = "\
+# Netscape HTTP Cookie File
+.example.com\tTRUE\t/\tFALSE\t0\tsession\tabc123
+wiki.example.com\tFALSE\t/docs\tTRUE\t0\tdocs\txyz
+#HttpOnly_.example.com\tTRUE\t/\tFALSE\t0\tsso\ts3cr3t
+other.com\tFALSE\t/\tFALSE\t0\tnope\t1
+.example.com\tTRUE\t/\tFALSE\t100\texpired\told
+malformed line
+";
+
+fn auth() -> RequestAuthArgs {
+    RequestAuthArgs {
+        headers: vec!["X-Team: infra".to_string(), "X-Api-Key: k3y".to_string()],
+        basic: Some("alice:hunter2".to_string()),
+        bearer: None,
+        cookie_jar: None,
+    }
+}
+
+fn build(auth: &RequestAuthArgs, url: &str) -> reqwest::Request {
+    let builder = reqwest::Client::new().get(url);
+    apply_auth(builder, auth, url).unwrap().build().unwrap()
+}
+
+#[test]
+fn hop_request_drops_auth_across_hosts() {
+    let client = reqwest::Client::new();
+    let origin = Url::parse("https://example.com/start").unwrap();
+
+    let same = Url::parse("https://example.com/next").unwrap();
+    let req = hop_request(&client, &origin, &same, &auth()).unwrap();
+    assert_eq!(req.headers()["X-Api-Key"], "k3y");
+
+    let default_port = Url::parse("https://example.com:443/next").unwrap();
+    let req = hop_request(&client, &origin, &default_port, &auth()).unwrap();
+    assert_eq!(req.headers()["X-Api-Key"], "k3y");
+
+    for other in [
+        "https://evil.test/next",
+        "http://example.com/next",
+        "https://example.com:8443/next",
+    ] {
+        let other = Url::parse(other).unwrap();
+        let req = hop_request(&client, &origin, &other, &auth()).unwrap();
+        assert!(req.headers().get("X-Api-Key").is_none());
+        assert!(req.headers().get("X-Team").is_none());
+        assert!(req.headers().get("Authorization").is_none());
+    }
+}
+
+#[test]
+fn parse_header_valid() {
+    let (name, value) = parse_header("X-Team:  infra ").unwrap();
+    assert_eq!(name, "X-Team");
+    assert_eq!(value, "infra");
+}
+
+#[test]
+fn parse_header_invalid_does_not_echo() {
+    let err = parse_header("secretvalue").unwrap_err().to_string();
+    assert!(!err.contains("secretvalue"));
+    assert!(parse_header(": value").is_err());
+}
+
+#[test]
+fn apply_headers_and_basic() {
+    let req = build(&auth(), "https://example.com/");
+    assert_eq!(req.headers()["X-Team"], "infra");
+    assert_eq!(req.headers()["X-Api-Key"], "k3y");
+    let authz = req.headers()["Authorization"].to_str().unwrap();
+    assert!(authz.starts_with("Basic "));
+}
+
+#[test]
+fn apply_bearer() {
+    let auth = RequestAuthArgs {
+        bearer: Some("tok".to_string()),
+        ..Default::default()
+    };
+    let req = build(&auth, "https://example.com/");
+    assert_eq!(req.headers()["Authorization"], "Bearer tok");
+}
+
+#[test]
+fn apply_basic_requires_colon() {
+    let auth = RequestAuthArgs {
+        basic: Some("alice".to_string()),
+        ..Default::default()
+    };
+    let builder = reqwest::Client::new().get("https://example.com/");
+    assert!(apply_auth(builder, &auth, "https://example.com/").is_err());
+}
+
+#[test]
+fn apply_cookie_jar() {
+    let dir = tempfile::tempdir().unwrap();
+    let jar = dir.path().join("cookies.txt");
+    fs::write(&jar, JAR).unwrap();
+    let auth = RequestAuthArgs {
+        cookie_jar: Some(jar.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+    let req = build(&auth, "https://example.com/page");
+    assert_eq!(req.headers()["Cookie"], "session=abc123; sso=s3cr3t");
+}
+
+#[test]
+fn apply_missing_cookie_jar_errors() {
+    let auth = RequestAuthArgs {
+        cookie_jar: Some("/nonexistent/cookies.txt".to_string()),
+        ..Default::default()
+    };
+    let builder = reqwest::Client::new().get("https://example.com/");
+    assert!(apply_auth(builder, &auth, "https://example.com/").is_err());
+}
+
+#[test]
+fn cookies_match_domain_path_and_secure() {
+    let cookies = cookies_for_url(JAR, "https://wiki.example.com/docs/a", 1000).unwrap();
+    assert_eq!(cookies, vec!["session=abc123", "docs=xyz", "sso=s3cr3t"]);
+
+    // Secure cookie is not sent over plain http
+    let cookies = cookies_for_url(JAR, "http://wiki.example.com/docs/a", 1000).unwrap();
+    assert!(!cookies.contains(&"docs=xyz".to_string()));
+
+    // Path must match
+    let cookies = cookies_for_url(JAR, "https://wiki.example.com/blog", 1000).unwrap();
+    assert!(!cookies.contains(&"docs=xyz".to_string()));
+    let cookies = cookies_for_url(JAR, "https://wiki.example.com/docsevil", 1000).unwrap();
+    assert!(!cookies.contains(&"docs=xyz".to_string()));
+    let cookies = cookies_for_url(JAR, "https://wiki.example.com/docs", 1000).unwrap();
+    assert!(cookies.contains(&"docs=xyz".to_string()));
+}
+
+#[test]
+fn cookies_skip_expired_and_other_domains() {
+    let cookies = cookies_for_url(JAR, "https://example.com/", 50).unwrap();
+    assert!(cookies.contains(&"expired=old".to_string()));
+    let cookies = cookies_for_url(JAR, "https://example.com/", 1000).unwrap();
+    assert!(!cookies.contains(&"expired=old".to_string()));
+    assert!(!cookies.contains(&"nope=1".to_string()));
+}
+
+#[test]
+fn cookies_invalid_url() {
+    assert!(cookies_for_url(JAR, "not a url", 0).is_err());
+}
+
+#[test]
+fn redact_replaces_secrets() {
+    let text = "alice:hunter2 sent k3y with team infra";
+    assert_eq!(redact(text, &auth()), "alice:*** sent *** with team infra");
+}
+
+#[test]
+fn has_credentials() {
+    assert!(!RequestAuthArgs::default().has_credentials());
+    assert!(auth().has_credentials());
+}
+
+#[test]
+fn redact_bearer() {
+    let auth = RequestAuthArgs {
+        bearer: Some("tok123".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(redact("Bearer tok123", &auth), "Bearer ***");
+}
+
+#[test]
+fn debug_hides_secrets() {
+    let mut auth = auth();
+    auth.bearer = Some("tok123".to_string());
+    let debug = format!("{:?}", auth);
+    assert!(!debug.contains("hunter2"));
+    assert!(!debug.contains("k3y"));
+    assert!(!debug.contains("tok123"));
+    assert!(debug.contains("X-Team: infra"));
+    assert!(debug.contains("alice:***"));
+}
@@ -0,0 +1,181 @@
+use crate::utils::cli::{Freshness, WebSearchArgs};
+
+/// Search text plus the domain and freshness filters applied to it
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    pub text: String,
+    pub sites: Vec<String>,
+    pub exclude_sites: Vec<String>,
+    pub freshness: Option<Freshness>,
+}
+
+impl SearchQuery {
+    /// Unfiltered query
+    #[cfg(test)]
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn from_args(args: &WebSearchArgs) -> Self {
+        Self {
+            text: args.query.clone(),
+            sites: args.site.iter().map(|s| normalize_domain(s)).collect(),
+            exclude_sites: args
+                .exclude_site
+                .iter()
+                .map(|s| normalize_domain(s))
+                .collect(),
+            freshness: args.freshness,
+        }
+    }
+
+    /// Query text with `site:` / `-site:` operators for the provider
+    pub fn provider_query(&self) -> String {
+        let mut parts = vec![self.text.clone()];
+        if !self.sites.is_empty() {
+            let sites: Vec<String> = self.sites.iter().map(|s| format!("site:{}", s)).collect();
+            parts.push(format!("({})", sites.join(" OR ")));
+        }
+        parts.extend(self.exclude_sites.iter().map(|s| format!("-site:{}", s)));
+        parts.join(" ")
+    }
+
+    /// Whether a result URL passes the domain filters (operators are only
+    /// hints to the provider, so results are checked again)
+    pub fn allows(&self, url: &str) -> bool {
+        if reqwest::Url::parse(url).is_err() {
+            return self.sites.is_empty();
+        }
+        (self.sites.is_empty() || host_matches(url, &self.sites))
+            && !host_matches(url, &self.exclude_sites)
+    }
+}
+
+/// Domains that may receive credentials: `--site` plus `--auth-host`
+pub fn auth_hosts(args: &WebSearchArgs) -> Vec<String> {
+    args.site
+        .iter()
+        .chain(&args.auth_host)
+        .map(|s| normalize_domain(s))
+        .collect()
+}
+
+/// Whether the URL's host is one of `domains` or a subdomain of one
+pub fn host_matches(url: &str, domains: &[String]) -> bool {
+    let Some(host) = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_lowercase))
+    else {
+        return false;
+    };
+    domains
+        .iter()
+        .any(|d| host == *d || host.ends_with(&format!(".{}", d)))
+}
+
+impl Freshness {
+    /// Brave Search `freshness` parameter value
+    pub fn brave_param(self) -> &'static str {
+        match self {
+            Self::Day => "pd",
+            Self::Week => "pw",
+            Self::Month => "pm",
+            Self::Year => "py",
+        }
+    }
+}
+
+/// Clap value parser for domain flags: rejects values with no host
+/// (e.g. `--site ""`), which would otherwise match every URL
+pub fn parse_domain(s: &str) -> Result<String, String> {
+    if normalize_domain(s).is_empty() {
+        return Err(format!("invalid domain '{}'", s));
+    }
+    Ok(s.to_string())
+}
+
+/// `https://www.Docs.rs/` -> `docs.rs`
+fn normalize_domain(domain: &str) -> String {
+    let domain = domain.trim().to_lowercase();
+    let domain = domain
+        .strip_prefix("https://")
+        .or_else(|| domain.strip_prefix("http://"))
+        .unwrap_or(&domain);
+    let domain = domain.split('/').next().unwrap_or(domain);
+    domain.strip_prefix("www.").unwrap_or(domain).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(sites: &[&str], exclude: &[&str]) -> SearchQuery {
+        SearchQuery {
+            text: "tokio select".to_string(),
+            sites: sites.iter().map(|s| s.to_string()).collect(),
+            exclude_sites: exclude.iter().map(|s| s.to_string()).collect(),
+            freshness: None,
+        }
+    }
+
+    #[test]
+    fn provider_query_operators() {
+        assert_eq!(query(&[], &[]).provider_query(), "tokio select");
+        assert_eq!(
+            query(&["docs.rs", "github.com"], &["pinterest.com"]).provider_query(),
+            "tokio select (site:docs.rs OR site:github.com) -site:pinterest.com"
+        );
+    }
+
+    #[test]
+    fn parse_domain_rejects_empty() {
+        assert_eq!(parse_domain("docs.rs"), Ok("docs.rs".to_string()));
+        assert!(parse_domain("").is_err());
+        assert!(parse_domain("  ").is_err());
+        assert!(parse_domain("https://").is_err());
+    }
+
+    #[test]
+    fn allows_matches_domain_and_subdomains() {
+        let q = query(&["docs.rs"], &[]);
+        assert!(q.allows("https://docs.rs/tokio"));
+        assert!(q.allows("https://api.docs.rs/x"));
+        assert!(!q.allows("https://notdocs.rs/x"));
+        assert!(!q.allows("not a url"));
+    }
+
+    #[test]
+    fn allows_drops_excluded_sites() {
+        let q = query(&[], &["pinterest.com"]);
+        assert!(!q.allows("https://www.pinterest.com/pin/1"));
+        assert!(q.allows("https://example.com"));
+        assert!(q.allows("not a url"));
+    }
+
+    #[test]
+    fn host_matches_domains() {
+        let domains = vec!["wiki.corp".to_string()];
+        assert!(host_matches("https://wiki.corp/page", &domains));
+        assert!(host_matches("https://eu.wiki.corp/page", &domains));
+        assert!(!host_matches("https://evil.test/?wiki.corp", &domains));
+        assert!(!host_matches("not a url", &domains));
+        assert!(!host_matches("https://wiki.corp/", &[]));
+    }
+
+    #[test]
+    fn normalize_domain_strips_scheme_www_and_path() {
+        assert_eq!(normalize_domain("https://www.Docs.rs/"), "docs.rs");
+        assert_eq!(normalize_domain("github.com/tokio-rs"), "github.com");
+    }
+
+    #[test]
+    fn freshness_params() {
+        assert_eq!(Freshness::Day.brave_param(), "pd");
+        assert_eq!(Freshness::Week.brave_param(), "pw");
+        assert_eq!(Freshness::Month.brave_param(), "pm");
+        assert_eq!(Freshness::Year.brave_param(), "py");
+    }
+}
@@ -2,9 +2,15 @@ use anyhow::{bail, Context, Result};
 use serde::Deserialize;
 use std::fs;
 
-use super::cli::WebSearchArgs;
+use super::cli::{RequestAuthArgs, WebSearchArgs};
 use super::fetch_html::extract_summary;
-use crate::util::{load_credentials, BraveCredentials};
+use super::request_auth::get_with_auth;
+use crate::util::{load_credentials, timings, BraveCredentials};
+
+mod filters;
+
+use filters::{auth_hosts, host_matches};
+pub use filters::{parse_domain, SearchQuery};
 
 #[cfg(test)]
 mod tests;
@@ -52,7 +58,7 @@ pub struct FetchedResult {
 /// Trait for Brave Search API operations
 #[async_trait::async_trait]
 pub trait BraveSearchApi {
-    async fn search(&self, query: &str, count: usize) -> Result<Vec<SearchResult>>;
+    async fn search(&self, query: &SearchQuery, count: usize) -> Result<Vec<SearchResult>>;
 }
 
 /// Production client for Brave Search
@@ -77,12 +83,16 @@ impl BraveSearchClient {
 
 #[async_trait::async_trait]
 impl BraveSearchApi for BraveSearchClient {
-    async fn search(&self, query: &str, count: usize) -> Result<Vec<SearchResult>> {
-        let url = format!(
+    async fn search(&self, query: &SearchQuery, count: usize) -> Result<Vec<SearchResult>> {
+        let _span = timings::span("api: brave search");
+        let mut url = format!(
             "https://api.search.brave.com/res/v1/web/search?q={}&count={}",
-            urlencoding::encode(query),
+            urlencoding::encode(&query.provider_query()),
             count
         );
+        if let Some(freshness) = query.freshness {
+            url.push_str(&format!("&freshness={}", freshness.brave_param()));
+        }
 
         let response = self
             .http
@@ -118,9 +128,20 @@ pub trait HttpFetcher {
     async fn fetch(&self, url: &str) -> Result<String>;
 }
 
+/// Sent to hosts outside the auth allowlist
+static NO_AUTH: RequestAuthArgs = RequestAuthArgs {
+    headers: Vec::new(),
+    basic: None,
+    bearer: None,
+    cookie_jar: None,
+};
+
 /// Production HTTP fetcher
 pub struct DefaultHttpFetcher {
     http: reqwest::Client,
+    auth: RequestAuthArgs,
+    /// Domains allowed to receive `auth`; results elsewhere are fetched without it
+    auth_hosts: Vec<String>,
 }
 
 impl Default for DefaultHttpFetcher {
@@ -134,21 +155,41 @@ impl DefaultHttpFetcher {
         let http = reqwest::Client::builder()
             .user_agent("hu-cli/0.1")
             .timeout(std::time::Duration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none())
             .build()
             .expect("Failed to build HTTP client");
-        Self { http }
+        Self {
+            http,
+            auth: RequestAuthArgs::default(),
+            auth_hosts: Vec::new(),
+        }
+    }
+
+    /// Fetcher that sends the given auth/headers/cookies to results on
+    /// `auth_hosts` (or their subdomains) only
+    pub fn with_auth(auth: RequestAuthArgs, auth_hosts: Vec<String>) -> Self {
+        Self {
+            auth,
+            auth_hosts,
+            ..Self::new()
+        }
+    }
+
+    /// Credentials to send to `url`
+    fn auth_for(&self, url: &str) -> &RequestAuthArgs {
+        if host_matches(url, &self.auth_hosts) {
+            &self.auth
+        } else {
+            &NO_AUTH
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl HttpFetcher for DefaultHttpFetcher {
     async fn fetch(&self, url: &str) -> Result<String> {
-        let response = self
-            .http
-            .get(url)
-            .send()
-            .await
-            .with_context(|| format!("Failed to fetch {}", url))?;
+        let _span = timings::span("api: fetch");
+        let response = get_with_auth(&self.http, url, self.auth_for(url)).await?;
 
         response
             .text()
@@ -165,14 +206,15 @@ impl HttpFetcher for DefaultHttpFetcher {
 pub async fn search_and_fetch(
     api: &impl BraveSearchApi,
     fetcher: &impl HttpFetcher,
-    query: &str,
+    query: &SearchQuery,
     count: usize,
     fetch_content: bool,
 ) -> Result<Vec<FetchedResult>> {
     let results = api.search(query, count).await?;
 
     let mut fetched = Vec::new();
-    for result in results.into_iter().take(count) {
+    let allowed = results.into_iter().filter(|r| query.allows(&r.url));
+    for result in allowed.take(count) {
         let content = if fetch_content {
             match fetcher.fetch(&result.url).await {
                 Ok(html) => Some(extract_summary(&html)),
@@ -233,13 +275,23 @@ pub async fn run(args: WebSearchArgs) -> Result<()> {
         .context("Brave API key not configured. Add [brave] section to credentials.toml")?;
 
     let client = BraveSearchClient::from_credentials(&brave);
-    let fetcher = DefaultHttpFetcher::new();
+    let query = SearchQuery::from_args(&args);
+    let hosts = auth_hosts(&args);
+    if hosts.is_empty() && args.auth.has_credentials() {
+        eprintln!(
+            "Warning: auth options ignored; name the hosts to send them to \
+             with --site or --auth-host"
+        );
+    }
+    let fetcher = DefaultHttpFetcher::with_auth(args.auth, hosts);
 
     let fetch_content = !args.list;
-    let results =
-        search_and_fetch(&client, &fetcher, &args.query, args.results, fetch_content).await?;
+    let results = search_and_fetch(&client, &fetcher, &query, args.results, fetch_content).await?;
 
-    let output = format_results(&results, fetch_content);
+    let output = {
+        let _span = timings::span("render");
+        format_results(&results, fetch_content)
+    };
 
     if let Some(path) = args.output {
         fs::write(&path, &output).with_context(|| format!("Failed to write to {}", path))?;
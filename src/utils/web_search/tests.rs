@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::request_auth::hop_request;
 
 // Mock implementations for testing
 struct MockBraveApi {
@@ -7,7 +8,7 @@ struct MockBraveApi {
 
 #[async_trait::async_trait]
 impl BraveSearchApi for MockBraveApi {
-    async fn search(&self, _query: &str, count: usize) -> Result<Vec<SearchResult>> {
+    async fn search(&self, _query: &SearchQuery, count: usize) -> Result<Vec<SearchResult>> {
         Ok(self.results.iter().take(count).cloned().collect())
     }
 }
@@ -129,6 +130,38 @@ fn default_http_fetcher_default() {
     let _ = format!("{:?}", fetcher.http);
 }
 
+#[test]
+fn default_http_fetcher_with_auth() {
+    let auth = RequestAuthArgs {
+        bearer: Some("tok".to_string()),
+        ..Default::default()
+    };
+    let fetcher = DefaultHttpFetcher::with_auth(auth, vec!["example.com".to_string()]);
+    assert_eq!(fetcher.auth.bearer.as_deref(), Some("tok"));
+}
+
+#[test]
+fn default_http_fetcher_sends_auth_to_allowed_hosts_only() {
+    let auth = RequestAuthArgs {
+        headers: vec!["X-Api-Key: k3y".to_string()],
+        bearer: Some("tok".to_string()),
+        ..Default::default()
+    };
+    let fetcher = DefaultHttpFetcher::with_auth(auth, vec!["wiki.corp".to_string()]);
+    let first_hop = |url: &str| {
+        let url = reqwest::Url::parse(url).unwrap();
+        hop_request(&fetcher.http, &url, &url, fetcher.auth_for(url.as_str())).unwrap()
+    };
+
+    let own = first_hop("https://docs.wiki.corp/page");
+    assert_eq!(own.headers()["X-Api-Key"], "k3y");
+    assert_eq!(own.headers()["Authorization"], "Bearer tok");
+
+    let foreign = first_hop("https://random-blog.example/post");
+    assert!(foreign.headers().get("X-Api-Key").is_none());
+    assert!(foreign.headers().get("Authorization").is_none());
+}
+
 #[tokio::test]
 async fn search_and_fetch_without_content() {
     let api = MockBraveApi {
@@ -138,7 +171,7 @@ async fn search_and_fetch_without_content() {
         content: "<p>Test</p>".to_string(),
     };
 
-    let results = search_and_fetch(&api, &fetcher, "test", 2, false)
+    let results = search_and_fetch(&api, &fetcher, &SearchQuery::new("test"), 2, false)
         .await
         .unwrap();
 
@@ -156,7 +189,7 @@ async fn search_and_fetch_with_content() {
         content: "<p>Fetched content here</p>".to_string(),
     };
 
-    let results = search_and_fetch(&api, &fetcher, "test", 2, true)
+    let results = search_and_fetch(&api, &fetcher, &SearchQuery::new("test"), 2, true)
         .await
         .unwrap();
 
@@ -172,7 +205,7 @@ async fn search_and_fetch_handles_fetch_failure() {
     };
     let fetcher = FailingFetcher;
 
-    let results = search_and_fetch(&api, &fetcher, "test", 2, true)
+    let results = search_and_fetch(&api, &fetcher, &SearchQuery::new("test"), 2, true)
         .await
         .unwrap();
 
@@ -189,13 +222,37 @@ async fn search_and_fetch_limits_results() {
         content: "<p>Test</p>".to_string(),
     };
 
-    let results = search_and_fetch(&api, &fetcher, "test", 1, false)
+    let results = search_and_fetch(&api, &fetcher, &SearchQuery::new("test"), 1, false)
         .await
         .unwrap();
 
     assert_eq!(results.len(), 1);
 }
 
+#[tokio::test]
+async fn search_and_fetch_applies_domain_filters() {
+    let mut results = sample_results();
+    results[1].url = "https://blocked.example.com/two".to_string();
+    results.push(SearchResult {
+        title: "Elsewhere".to_string(),
+        url: "https://other.org/".to_string(),
+        description: String::new(),
+    });
+    let api = MockBraveApi { results };
+    let query = SearchQuery {
+        sites: vec!["example.com".to_string()],
+        exclude_sites: vec!["blocked.example.com".to_string()],
+        ..SearchQuery::new("test")
+    };
+
+    let results = search_and_fetch(&api, &FailingFetcher, &query, 10, false)
+        .await
+        .unwrap();
+
+    let titles: Vec<_> = results.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, vec!["Result One", "Result Three"]);
+}
+
 #[test]
 fn format_results_list_mode() {
     let results = vec![
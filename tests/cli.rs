@@ -10,7 +10,7 @@ fn no_args_shows_help_and_exits_zero() {
 
     assert!(output.status.success(), "expected exit code 0");
     let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(stdout.contains("Usage: hu [COMMAND]"));
+    assert!(stdout.contains("Usage: hu [OPTIONS] [COMMAND]"));
     assert!(stdout.contains("Commands:"));
 }
 
@@ -65,8 +65,8 @@ fn all_main_commands_in_help() {
     let stdout = String::from_utf8_lossy(&output.stdout);
 
     let commands = [
-        "newrelic", "utils", "context", "read", "data", "install", "docs", "cron", "shell",
-        "mcp", "setup",
+        "newrelic", "utils", "context", "read", "data", "install", "docs", "cron", "shell", "mcp",
+        "setup",
     ];
     for cmd in commands {
         assert!(stdout.contains(cmd), "help missing command: {}", cmd);
@@ -156,6 +156,36 @@ fn utils_grep_executes() {
     assert!(stdout.contains("main.rs"));
 }
 
+#[test]
+fn utils_grep_unique_conflicts_with_unique_by() {
+    let output = hu()
+        .args([
+            "utils",
+            "grep",
+            "fn",
+            "src/main.rs",
+            "--unique",
+            "--unique-by",
+            "file",
+        ])
+        .output()
+        .expect("failed to execute");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot be used with"));
+}
+
+#[test]
+fn utils_web_search_rejects_empty_site() {
+    let output = hu()
+        .args(["utils", "web-search", "tokio", "--site", ""])
+        .output()
+        .expect("failed to execute");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("invalid domain"));
+}
+
 #[test]
 fn utils_grep_refs_mode() {
     let output = hu()